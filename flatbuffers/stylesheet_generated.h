@@ -54,6 +54,15 @@ struct ObjectBuilder;
 struct DeclarationTuple;
 struct DeclarationTupleBuilder;
 
+struct AttributeSelector;
+struct AttributeSelectorBuilder;
+
+struct PseudoSelector;
+struct PseudoSelectorBuilder;
+
+struct CompoundSelector;
+struct CompoundSelectorBuilder;
+
 struct Selector;
 struct SelectorBuilder;
 
@@ -66,6 +75,12 @@ struct KeyValueStringBuilder;
 struct Style;
 struct StyleBuilder;
 
+struct FontSource;
+struct FontSourceBuilder;
+
+struct UnicodeRange;
+struct UnicodeRangeBuilder;
+
 struct Font;
 struct FontBuilder;
 
@@ -87,6 +102,9 @@ struct ConditionBuilder;
 struct Media;
 struct MediaBuilder;
 
+struct Container;
+struct ContainerBuilder;
+
 struct StyleSheet;
 struct StyleSheetBuilder;
 
@@ -942,13 +960,14 @@ inline ::flatbuffers::Offset<Object> CreateObjectDirect(
 struct DeclarationTuple FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   typedef DeclarationTupleBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
-    VT_PROPERTY_ID = 4,
+    VT_PROPERTY_ID_V1 = 4,
     VT_VALUE_TYPE = 6,
     VT_VALUE = 8,
-    VT_FLAG = 10
+    VT_FLAG = 10,
+    VT_PROPERTY_ID = 12
   };
-  uint8_t property_id() const {
-    return GetField<uint8_t>(VT_PROPERTY_ID, 0);
+  uint8_t property_id_v1() const {
+    return GetField<uint8_t>(VT_PROPERTY_ID_V1, 0);
   }
   Styles::Value value_type() const {
     return static_cast<Styles::Value>(GetField<uint8_t>(VT_VALUE_TYPE, 0));
@@ -993,13 +1012,17 @@ struct DeclarationTuple FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   uint8_t flag() const {
     return GetField<uint8_t>(VT_FLAG, 0);
   }
+  uint16_t property_id() const {
+    return GetField<uint16_t>(VT_PROPERTY_ID, 0);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
-           VerifyField<uint8_t>(verifier, VT_PROPERTY_ID, 1) &&
+           VerifyField<uint8_t>(verifier, VT_PROPERTY_ID_V1, 1) &&
            VerifyField<uint8_t>(verifier, VT_VALUE_TYPE, 1) &&
            VerifyOffset(verifier, VT_VALUE) &&
            VerifyValue(verifier, value(), value_type()) &&
            VerifyField<uint8_t>(verifier, VT_FLAG, 1) &&
+           VerifyField<uint16_t>(verifier, VT_PROPERTY_ID, 2) &&
            verifier.EndTable();
   }
 };
@@ -1052,8 +1075,8 @@ struct DeclarationTupleBuilder {
   typedef DeclarationTuple Table;
   ::flatbuffers::FlatBufferBuilder &fbb_;
   ::flatbuffers::uoffset_t start_;
-  void add_property_id(uint8_t property_id) {
-    fbb_.AddElement<uint8_t>(DeclarationTuple::VT_PROPERTY_ID, property_id, 0);
+  void add_property_id_v1(uint8_t property_id_v1) {
+    fbb_.AddElement<uint8_t>(DeclarationTuple::VT_PROPERTY_ID_V1, property_id_v1, 0);
   }
   void add_value_type(Styles::Value value_type) {
     fbb_.AddElement<uint8_t>(DeclarationTuple::VT_VALUE_TYPE, static_cast<uint8_t>(value_type), 0);
@@ -1064,6 +1087,9 @@ struct DeclarationTupleBuilder {
   void add_flag(uint8_t flag) {
     fbb_.AddElement<uint8_t>(DeclarationTuple::VT_FLAG, flag, 0);
   }
+  void add_property_id(uint16_t property_id) {
+    fbb_.AddElement<uint16_t>(DeclarationTuple::VT_PROPERTY_ID, property_id, 0);
+  }
   explicit DeclarationTupleBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -1077,24 +1103,319 @@ struct DeclarationTupleBuilder {
 
 inline ::flatbuffers::Offset<DeclarationTuple> CreateDeclarationTuple(
     ::flatbuffers::FlatBufferBuilder &_fbb,
-    uint8_t property_id = 0,
+    uint8_t property_id_v1 = 0,
     Styles::Value value_type = Styles::Value_NONE,
     ::flatbuffers::Offset<void> value = 0,
-    uint8_t flag = 0) {
+    uint8_t flag = 0,
+    uint16_t property_id = 0) {
   DeclarationTupleBuilder builder_(_fbb);
   builder_.add_value(value);
+  builder_.add_property_id(property_id);
   builder_.add_flag(flag);
   builder_.add_value_type(value_type);
-  builder_.add_property_id(property_id);
+  builder_.add_property_id_v1(property_id_v1);
+  return builder_.Finish();
+}
+
+struct AttributeSelector FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef AttributeSelectorBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_NAME = 4,
+    VT_OPERATOR_ = 6,
+    VT_VALUE = 8,
+    VT_CASE_INSENSITIVE = 10
+  };
+  const ::flatbuffers::String *name() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_NAME);
+  }
+  uint8_t operator_() const {
+    return GetField<uint8_t>(VT_OPERATOR_, 0);
+  }
+  const ::flatbuffers::String *value() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_VALUE);
+  }
+  bool case_insensitive() const {
+    return GetField<uint8_t>(VT_CASE_INSENSITIVE, 0) != 0;
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_NAME) &&
+           verifier.VerifyString(name()) &&
+           VerifyField<uint8_t>(verifier, VT_OPERATOR_, 1) &&
+           VerifyOffset(verifier, VT_VALUE) &&
+           verifier.VerifyString(value()) &&
+           VerifyField<uint8_t>(verifier, VT_CASE_INSENSITIVE, 1) &&
+           verifier.EndTable();
+  }
+};
+
+struct AttributeSelectorBuilder {
+  typedef AttributeSelector Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_name(::flatbuffers::Offset<::flatbuffers::String> name) {
+    fbb_.AddOffset(AttributeSelector::VT_NAME, name);
+  }
+  void add_operator_(uint8_t operator_) {
+    fbb_.AddElement<uint8_t>(AttributeSelector::VT_OPERATOR_, operator_, 0);
+  }
+  void add_value(::flatbuffers::Offset<::flatbuffers::String> value) {
+    fbb_.AddOffset(AttributeSelector::VT_VALUE, value);
+  }
+  void add_case_insensitive(bool case_insensitive) {
+    fbb_.AddElement<uint8_t>(AttributeSelector::VT_CASE_INSENSITIVE, static_cast<uint8_t>(case_insensitive), 0);
+  }
+  explicit AttributeSelectorBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<AttributeSelector> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<AttributeSelector>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<AttributeSelector> CreateAttributeSelector(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> name = 0,
+    uint8_t operator_ = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> value = 0,
+    bool case_insensitive = false) {
+  AttributeSelectorBuilder builder_(_fbb);
+  builder_.add_value(value);
+  builder_.add_name(name);
+  builder_.add_case_insensitive(case_insensitive);
+  builder_.add_operator_(operator_);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<AttributeSelector> CreateAttributeSelectorDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *name = nullptr,
+    uint8_t operator_ = 0,
+    const char *value = nullptr,
+    bool case_insensitive = false) {
+  auto name__ = name ? _fbb.CreateString(name) : 0;
+  auto value__ = value ? _fbb.CreateString(value) : 0;
+  return Styles::CreateAttributeSelector(
+      _fbb,
+      name__,
+      operator_,
+      value__,
+      case_insensitive);
+}
+
+struct PseudoSelector FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef PseudoSelectorBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_PSEUDO = 4,
+    VT_PSEUDO_KEY = 6,
+    VT_PSEUDO_VAL = 8
+  };
+  uint8_t pseudo() const {
+    return GetField<uint8_t>(VT_PSEUDO, 0);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoKey>> *pseudo_key() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoKey>> *>(VT_PSEUDO_KEY);
+  }
+  const ::flatbuffers::String *pseudo_val() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_PSEUDO_VAL);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<uint8_t>(verifier, VT_PSEUDO, 1) &&
+           VerifyOffset(verifier, VT_PSEUDO_KEY) &&
+           verifier.VerifyVector(pseudo_key()) &&
+           verifier.VerifyVectorOfTables(pseudo_key()) &&
+           VerifyOffset(verifier, VT_PSEUDO_VAL) &&
+           verifier.VerifyString(pseudo_val()) &&
+           verifier.EndTable();
+  }
+};
+
+struct PseudoSelectorBuilder {
+  typedef PseudoSelector Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_pseudo(uint8_t pseudo) {
+    fbb_.AddElement<uint8_t>(PseudoSelector::VT_PSEUDO, pseudo, 0);
+  }
+  void add_pseudo_key(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoKey>>> pseudo_key) {
+    fbb_.AddOffset(PseudoSelector::VT_PSEUDO_KEY, pseudo_key);
+  }
+  void add_pseudo_val(::flatbuffers::Offset<::flatbuffers::String> pseudo_val) {
+    fbb_.AddOffset(PseudoSelector::VT_PSEUDO_VAL, pseudo_val);
+  }
+  explicit PseudoSelectorBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<PseudoSelector> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<PseudoSelector>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<PseudoSelector> CreatePseudoSelector(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    uint8_t pseudo = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoKey>>> pseudo_key = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> pseudo_val = 0) {
+  PseudoSelectorBuilder builder_(_fbb);
+  builder_.add_pseudo_val(pseudo_val);
+  builder_.add_pseudo_key(pseudo_key);
+  builder_.add_pseudo(pseudo);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<PseudoSelector> CreatePseudoSelectorDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    uint8_t pseudo = 0,
+    const std::vector<::flatbuffers::Offset<Styles::PseudoKey>> *pseudo_key = nullptr,
+    const char *pseudo_val = nullptr) {
+  auto pseudo_key__ = pseudo_key ? _fbb.CreateVector<::flatbuffers::Offset<Styles::PseudoKey>>(*pseudo_key) : 0;
+  auto pseudo_val__ = pseudo_val ? _fbb.CreateString(pseudo_val) : 0;
+  return Styles::CreatePseudoSelector(
+      _fbb,
+      pseudo,
+      pseudo_key__,
+      pseudo_val__);
+}
+
+struct CompoundSelector FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef CompoundSelectorBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_TAG = 4,
+    VT_ID = 6,
+    VT_CLASSES = 8,
+    VT_ATTRIBUTES = 10,
+    VT_NEGATIONS = 12,
+    VT_PSEUDOS = 14
+  };
+  const ::flatbuffers::String *tag() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_TAG);
+  }
+  const ::flatbuffers::String *id() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_ID);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<::flatbuffers::String>> *classes() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<::flatbuffers::String>> *>(VT_CLASSES);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::AttributeSelector>> *attributes() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::AttributeSelector>> *>(VT_ATTRIBUTES);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::CompoundSelector>> *negations() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::CompoundSelector>> *>(VT_NEGATIONS);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoSelector>> *pseudos() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoSelector>> *>(VT_PSEUDOS);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_TAG) &&
+           verifier.VerifyString(tag()) &&
+           VerifyOffset(verifier, VT_ID) &&
+           verifier.VerifyString(id()) &&
+           VerifyOffset(verifier, VT_CLASSES) &&
+           verifier.VerifyVector(classes()) &&
+           verifier.VerifyVectorOfStrings(classes()) &&
+           VerifyOffset(verifier, VT_ATTRIBUTES) &&
+           verifier.VerifyVector(attributes()) &&
+           verifier.VerifyVectorOfTables(attributes()) &&
+           VerifyOffset(verifier, VT_NEGATIONS) &&
+           verifier.VerifyVector(negations()) &&
+           verifier.VerifyVectorOfTables(negations()) &&
+           VerifyOffset(verifier, VT_PSEUDOS) &&
+           verifier.VerifyVector(pseudos()) &&
+           verifier.VerifyVectorOfTables(pseudos()) &&
+           verifier.EndTable();
+  }
+};
+
+struct CompoundSelectorBuilder {
+  typedef CompoundSelector Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_tag(::flatbuffers::Offset<::flatbuffers::String> tag) {
+    fbb_.AddOffset(CompoundSelector::VT_TAG, tag);
+  }
+  void add_id(::flatbuffers::Offset<::flatbuffers::String> id) {
+    fbb_.AddOffset(CompoundSelector::VT_ID, id);
+  }
+  void add_classes(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<::flatbuffers::String>>> classes) {
+    fbb_.AddOffset(CompoundSelector::VT_CLASSES, classes);
+  }
+  void add_attributes(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::AttributeSelector>>> attributes) {
+    fbb_.AddOffset(CompoundSelector::VT_ATTRIBUTES, attributes);
+  }
+  void add_negations(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::CompoundSelector>>> negations) {
+    fbb_.AddOffset(CompoundSelector::VT_NEGATIONS, negations);
+  }
+  void add_pseudos(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoSelector>>> pseudos) {
+    fbb_.AddOffset(CompoundSelector::VT_PSEUDOS, pseudos);
+  }
+  explicit CompoundSelectorBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<CompoundSelector> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<CompoundSelector>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<CompoundSelector> CreateCompoundSelector(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> tag = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> id = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<::flatbuffers::String>>> classes = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::AttributeSelector>>> attributes = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::CompoundSelector>>> negations = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoSelector>>> pseudos = 0) {
+  CompoundSelectorBuilder builder_(_fbb);
+  builder_.add_pseudos(pseudos);
+  builder_.add_negations(negations);
+  builder_.add_attributes(attributes);
+  builder_.add_classes(classes);
+  builder_.add_id(id);
+  builder_.add_tag(tag);
   return builder_.Finish();
 }
 
+inline ::flatbuffers::Offset<CompoundSelector> CreateCompoundSelectorDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *tag = nullptr,
+    const char *id = nullptr,
+    const std::vector<::flatbuffers::Offset<::flatbuffers::String>> *classes = nullptr,
+    const std::vector<::flatbuffers::Offset<Styles::AttributeSelector>> *attributes = nullptr,
+    const std::vector<::flatbuffers::Offset<Styles::CompoundSelector>> *negations = nullptr,
+    const std::vector<::flatbuffers::Offset<Styles::PseudoSelector>> *pseudos = nullptr) {
+  auto tag__ = tag ? _fbb.CreateString(tag) : 0;
+  auto id__ = id ? _fbb.CreateString(id) : 0;
+  auto classes__ = classes ? _fbb.CreateVector<::flatbuffers::Offset<::flatbuffers::String>>(*classes) : 0;
+  auto attributes__ = attributes ? _fbb.CreateVector<::flatbuffers::Offset<Styles::AttributeSelector>>(*attributes) : 0;
+  auto negations__ = negations ? _fbb.CreateVector<::flatbuffers::Offset<Styles::CompoundSelector>>(*negations) : 0;
+  auto pseudos__ = pseudos ? _fbb.CreateVector<::flatbuffers::Offset<Styles::PseudoSelector>>(*pseudos) : 0;
+  return Styles::CreateCompoundSelector(
+      _fbb,
+      tag__,
+      id__,
+      classes__,
+      attributes__,
+      negations__,
+      pseudos__);
+}
+
 struct Selector FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   typedef SelectorBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
     VT_STRING_VALUE = 4,
     VT_INTEGER_VALUE = 6,
-    VT_IS_STRING = 8
+    VT_IS_STRING = 8,
+    VT_COMPOUND = 10
   };
   const ::flatbuffers::String *string_value() const {
     return GetPointer<const ::flatbuffers::String *>(VT_STRING_VALUE);
@@ -1105,12 +1426,17 @@ struct Selector FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   bool is_string() const {
     return GetField<uint8_t>(VT_IS_STRING, 0) != 0;
   }
+  const Styles::CompoundSelector *compound() const {
+    return GetPointer<const Styles::CompoundSelector *>(VT_COMPOUND);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyOffset(verifier, VT_STRING_VALUE) &&
            verifier.VerifyString(string_value()) &&
            VerifyField<uint8_t>(verifier, VT_INTEGER_VALUE, 1) &&
            VerifyField<uint8_t>(verifier, VT_IS_STRING, 1) &&
+           VerifyOffset(verifier, VT_COMPOUND) &&
+           verifier.VerifyTable(compound()) &&
            verifier.EndTable();
   }
 };
@@ -1128,6 +1454,9 @@ struct SelectorBuilder {
   void add_is_string(bool is_string) {
     fbb_.AddElement<uint8_t>(Selector::VT_IS_STRING, static_cast<uint8_t>(is_string), 0);
   }
+  void add_compound(::flatbuffers::Offset<Styles::CompoundSelector> compound) {
+    fbb_.AddOffset(Selector::VT_COMPOUND, compound);
+  }
   explicit SelectorBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -1143,8 +1472,10 @@ inline ::flatbuffers::Offset<Selector> CreateSelector(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     ::flatbuffers::Offset<::flatbuffers::String> string_value = 0,
     uint8_t integer_value = 0,
-    bool is_string = false) {
+    bool is_string = false,
+    ::flatbuffers::Offset<Styles::CompoundSelector> compound = 0) {
   SelectorBuilder builder_(_fbb);
+  builder_.add_compound(compound);
   builder_.add_string_value(string_value);
   builder_.add_is_string(is_string);
   builder_.add_integer_value(integer_value);
@@ -1155,24 +1486,27 @@ inline ::flatbuffers::Offset<Selector> CreateSelectorDirect(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     const char *string_value = nullptr,
     uint8_t integer_value = 0,
-    bool is_string = false) {
+    bool is_string = false,
+    ::flatbuffers::Offset<Styles::CompoundSelector> compound = 0) {
   auto string_value__ = string_value ? _fbb.CreateString(string_value) : 0;
   return Styles::CreateSelector(
       _fbb,
       string_value__,
       integer_value,
-      is_string);
+      is_string,
+      compound);
 }
 
 struct PseudoKey FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   typedef PseudoKeyBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
-    VT_INTEGER_VALUE = 4,
+    VT_INTEGER_VALUE_V1 = 4,
     VT_BOOL_VALUE = 6,
-    VT_IS_INT = 8
+    VT_IS_INT = 8,
+    VT_INTEGER_VALUE = 10
   };
-  int8_t integer_value() const {
-    return GetField<int8_t>(VT_INTEGER_VALUE, 0);
+  int8_t integer_value_v1() const {
+    return GetField<int8_t>(VT_INTEGER_VALUE_V1, 0);
   }
   bool bool_value() const {
     return GetField<uint8_t>(VT_BOOL_VALUE, 0) != 0;
@@ -1180,11 +1514,15 @@ struct PseudoKey FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   bool is_int() const {
     return GetField<uint8_t>(VT_IS_INT, 0) != 0;
   }
+  int32_t integer_value() const {
+    return GetField<int32_t>(VT_INTEGER_VALUE, 0);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
-           VerifyField<int8_t>(verifier, VT_INTEGER_VALUE, 1) &&
+           VerifyField<int8_t>(verifier, VT_INTEGER_VALUE_V1, 1) &&
            VerifyField<uint8_t>(verifier, VT_BOOL_VALUE, 1) &&
            VerifyField<uint8_t>(verifier, VT_IS_INT, 1) &&
+           VerifyField<int32_t>(verifier, VT_INTEGER_VALUE, 4) &&
            verifier.EndTable();
   }
 };
@@ -1193,8 +1531,8 @@ struct PseudoKeyBuilder {
   typedef PseudoKey Table;
   ::flatbuffers::FlatBufferBuilder &fbb_;
   ::flatbuffers::uoffset_t start_;
-  void add_integer_value(int8_t integer_value) {
-    fbb_.AddElement<int8_t>(PseudoKey::VT_INTEGER_VALUE, integer_value, 0);
+  void add_integer_value_v1(int8_t integer_value_v1) {
+    fbb_.AddElement<int8_t>(PseudoKey::VT_INTEGER_VALUE_V1, integer_value_v1, 0);
   }
   void add_bool_value(bool bool_value) {
     fbb_.AddElement<uint8_t>(PseudoKey::VT_BOOL_VALUE, static_cast<uint8_t>(bool_value), 0);
@@ -1202,6 +1540,9 @@ struct PseudoKeyBuilder {
   void add_is_int(bool is_int) {
     fbb_.AddElement<uint8_t>(PseudoKey::VT_IS_INT, static_cast<uint8_t>(is_int), 0);
   }
+  void add_integer_value(int32_t integer_value) {
+    fbb_.AddElement<int32_t>(PseudoKey::VT_INTEGER_VALUE, integer_value, 0);
+  }
   explicit PseudoKeyBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -1215,13 +1556,15 @@ struct PseudoKeyBuilder {
 
 inline ::flatbuffers::Offset<PseudoKey> CreatePseudoKey(
     ::flatbuffers::FlatBufferBuilder &_fbb,
-    int8_t integer_value = 0,
+    int8_t integer_value_v1 = 0,
     bool bool_value = false,
-    bool is_int = false) {
+    bool is_int = false,
+    int32_t integer_value = 0) {
   PseudoKeyBuilder builder_(_fbb);
+  builder_.add_integer_value(integer_value);
   builder_.add_is_int(is_int);
   builder_.add_bool_value(bool_value);
-  builder_.add_integer_value(integer_value);
+  builder_.add_integer_value_v1(integer_value_v1);
   return builder_.Finish();
 }
 
@@ -1294,18 +1637,25 @@ struct Style FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   typedef StyleBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
     VT_DECLARATIONS = 4,
-    VT_MEDIA = 6,
+    VT_MEDIA_V1 = 6,
     VT_PSEUDO = 8,
     VT_PSEUDO_KEY = 10,
     VT_PSEUDO_VAL = 12,
     VT_SELECTOR = 14,
-    VT_VARIABLES = 16
+    VT_VARIABLES = 16,
+    VT_HAS_ENV = 18,
+    VT_MEDIA = 20,
+    VT_SPECIFICITY = 22,
+    VT_ORDER = 24,
+    VT_LAYER = 26,
+    VT_CONTAINER = 28,
+    VT_IMPORTANT_LAYER = 30
   };
   const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::DeclarationTuple>> *declarations() const {
     return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::DeclarationTuple>> *>(VT_DECLARATIONS);
   }
-  uint8_t media() const {
-    return GetField<uint8_t>(VT_MEDIA, 0);
+  uint8_t media_v1() const {
+    return GetField<uint8_t>(VT_MEDIA_V1, 0);
   }
   uint8_t pseudo() const {
     return GetField<uint8_t>(VT_PSEUDO, 0);
@@ -1322,12 +1672,33 @@ struct Style FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyValueString>> *variables() const {
     return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyValueString>> *>(VT_VARIABLES);
   }
+  bool has_env() const {
+    return GetField<uint8_t>(VT_HAS_ENV, 0) != 0;
+  }
+  uint32_t media() const {
+    return GetField<uint32_t>(VT_MEDIA, 0);
+  }
+  uint32_t specificity() const {
+    return GetField<uint32_t>(VT_SPECIFICITY, 0);
+  }
+  uint32_t order() const {
+    return GetField<uint32_t>(VT_ORDER, 0);
+  }
+  uint32_t layer() const {
+    return GetField<uint32_t>(VT_LAYER, 0);
+  }
+  uint32_t container() const {
+    return GetField<uint32_t>(VT_CONTAINER, 0);
+  }
+  uint32_t important_layer() const {
+    return GetField<uint32_t>(VT_IMPORTANT_LAYER, 0);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyOffset(verifier, VT_DECLARATIONS) &&
            verifier.VerifyVector(declarations()) &&
            verifier.VerifyVectorOfTables(declarations()) &&
-           VerifyField<uint8_t>(verifier, VT_MEDIA, 1) &&
+           VerifyField<uint8_t>(verifier, VT_MEDIA_V1, 1) &&
            VerifyField<uint8_t>(verifier, VT_PSEUDO, 1) &&
            VerifyOffset(verifier, VT_PSEUDO_KEY) &&
            verifier.VerifyVector(pseudo_key()) &&
@@ -1340,6 +1711,13 @@ struct Style FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
            VerifyOffset(verifier, VT_VARIABLES) &&
            verifier.VerifyVector(variables()) &&
            verifier.VerifyVectorOfTables(variables()) &&
+           VerifyField<uint8_t>(verifier, VT_HAS_ENV, 1) &&
+           VerifyField<uint32_t>(verifier, VT_MEDIA, 4) &&
+           VerifyField<uint32_t>(verifier, VT_SPECIFICITY, 4) &&
+           VerifyField<uint32_t>(verifier, VT_ORDER, 4) &&
+           VerifyField<uint32_t>(verifier, VT_LAYER, 4) &&
+           VerifyField<uint32_t>(verifier, VT_CONTAINER, 4) &&
+           VerifyField<uint32_t>(verifier, VT_IMPORTANT_LAYER, 4) &&
            verifier.EndTable();
   }
 };
@@ -1351,8 +1729,8 @@ struct StyleBuilder {
   void add_declarations(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::DeclarationTuple>>> declarations) {
     fbb_.AddOffset(Style::VT_DECLARATIONS, declarations);
   }
-  void add_media(uint8_t media) {
-    fbb_.AddElement<uint8_t>(Style::VT_MEDIA, media, 0);
+  void add_media_v1(uint8_t media_v1) {
+    fbb_.AddElement<uint8_t>(Style::VT_MEDIA_V1, media_v1, 0);
   }
   void add_pseudo(uint8_t pseudo) {
     fbb_.AddElement<uint8_t>(Style::VT_PSEUDO, pseudo, 0);
@@ -1369,6 +1747,27 @@ struct StyleBuilder {
   void add_variables(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyValueString>>> variables) {
     fbb_.AddOffset(Style::VT_VARIABLES, variables);
   }
+  void add_has_env(bool has_env) {
+    fbb_.AddElement<uint8_t>(Style::VT_HAS_ENV, static_cast<uint8_t>(has_env), 0);
+  }
+  void add_media(uint32_t media) {
+    fbb_.AddElement<uint32_t>(Style::VT_MEDIA, media, 0);
+  }
+  void add_specificity(uint32_t specificity) {
+    fbb_.AddElement<uint32_t>(Style::VT_SPECIFICITY, specificity, 0);
+  }
+  void add_order(uint32_t order) {
+    fbb_.AddElement<uint32_t>(Style::VT_ORDER, order, 0);
+  }
+  void add_layer(uint32_t layer) {
+    fbb_.AddElement<uint32_t>(Style::VT_LAYER, layer, 0);
+  }
+  void add_container(uint32_t container) {
+    fbb_.AddElement<uint32_t>(Style::VT_CONTAINER, container, 0);
+  }
+  void add_important_layer(uint32_t important_layer) {
+    fbb_.AddElement<uint32_t>(Style::VT_IMPORTANT_LAYER, important_layer, 0);
+  }
   explicit StyleBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -1383,32 +1782,53 @@ struct StyleBuilder {
 inline ::flatbuffers::Offset<Style> CreateStyle(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::DeclarationTuple>>> declarations = 0,
-    uint8_t media = 0,
+    uint8_t media_v1 = 0,
     uint8_t pseudo = 0,
     ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::PseudoKey>>> pseudo_key = 0,
     ::flatbuffers::Offset<::flatbuffers::String> pseudo_val = 0,
     ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Selector>>> selector = 0,
-    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyValueString>>> variables = 0) {
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyValueString>>> variables = 0,
+    bool has_env = false,
+    uint32_t media = 0,
+    uint32_t specificity = 0,
+    uint32_t order = 0,
+    uint32_t layer = 0,
+    uint32_t container = 0,
+    uint32_t important_layer = 0) {
   StyleBuilder builder_(_fbb);
+  builder_.add_important_layer(important_layer);
+  builder_.add_container(container);
+  builder_.add_layer(layer);
+  builder_.add_order(order);
+  builder_.add_specificity(specificity);
+  builder_.add_media(media);
   builder_.add_variables(variables);
   builder_.add_selector(selector);
   builder_.add_pseudo_val(pseudo_val);
   builder_.add_pseudo_key(pseudo_key);
   builder_.add_declarations(declarations);
+  builder_.add_has_env(has_env);
   builder_.add_pseudo(pseudo);
-  builder_.add_media(media);
+  builder_.add_media_v1(media_v1);
   return builder_.Finish();
 }
 
 inline ::flatbuffers::Offset<Style> CreateStyleDirect(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     const std::vector<::flatbuffers::Offset<Styles::DeclarationTuple>> *declarations = nullptr,
-    uint8_t media = 0,
+    uint8_t media_v1 = 0,
     uint8_t pseudo = 0,
     const std::vector<::flatbuffers::Offset<Styles::PseudoKey>> *pseudo_key = nullptr,
     const char *pseudo_val = nullptr,
     const std::vector<::flatbuffers::Offset<Styles::Selector>> *selector = nullptr,
-    const std::vector<::flatbuffers::Offset<Styles::KeyValueString>> *variables = nullptr) {
+    const std::vector<::flatbuffers::Offset<Styles::KeyValueString>> *variables = nullptr,
+    bool has_env = false,
+    uint32_t media = 0,
+    uint32_t specificity = 0,
+    uint32_t order = 0,
+    uint32_t layer = 0,
+    uint32_t container = 0,
+    uint32_t important_layer = 0) {
   auto declarations__ = declarations ? _fbb.CreateVector<::flatbuffers::Offset<Styles::DeclarationTuple>>(*declarations) : 0;
   auto pseudo_key__ = pseudo_key ? _fbb.CreateVector<::flatbuffers::Offset<Styles::PseudoKey>>(*pseudo_key) : 0;
   auto pseudo_val__ = pseudo_val ? _fbb.CreateString(pseudo_val) : 0;
@@ -1417,19 +1837,163 @@ inline ::flatbuffers::Offset<Style> CreateStyleDirect(
   return Styles::CreateStyle(
       _fbb,
       declarations__,
-      media,
+      media_v1,
       pseudo,
       pseudo_key__,
       pseudo_val__,
       selector__,
-      variables__);
+      variables__,
+      has_env,
+      media,
+      specificity,
+      order,
+      layer,
+      container,
+      important_layer);
+}
+
+struct FontSource FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef FontSourceBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_URL = 4,
+    VT_LOCAL = 6,
+    VT_FORMAT = 8
+  };
+  const ::flatbuffers::String *url() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_URL);
+  }
+  const ::flatbuffers::String *local() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_LOCAL);
+  }
+  const ::flatbuffers::String *format() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_FORMAT);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_URL) &&
+           verifier.VerifyString(url()) &&
+           VerifyOffset(verifier, VT_LOCAL) &&
+           verifier.VerifyString(local()) &&
+           VerifyOffset(verifier, VT_FORMAT) &&
+           verifier.VerifyString(format()) &&
+           verifier.EndTable();
+  }
+};
+
+struct FontSourceBuilder {
+  typedef FontSource Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_url(::flatbuffers::Offset<::flatbuffers::String> url) {
+    fbb_.AddOffset(FontSource::VT_URL, url);
+  }
+  void add_local(::flatbuffers::Offset<::flatbuffers::String> local) {
+    fbb_.AddOffset(FontSource::VT_LOCAL, local);
+  }
+  void add_format(::flatbuffers::Offset<::flatbuffers::String> format) {
+    fbb_.AddOffset(FontSource::VT_FORMAT, format);
+  }
+  explicit FontSourceBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<FontSource> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<FontSource>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<FontSource> CreateFontSource(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> url = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> local = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> format = 0) {
+  FontSourceBuilder builder_(_fbb);
+  builder_.add_format(format);
+  builder_.add_local(local);
+  builder_.add_url(url);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<FontSource> CreateFontSourceDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *url = nullptr,
+    const char *local = nullptr,
+    const char *format = nullptr) {
+  auto url__ = url ? _fbb.CreateString(url) : 0;
+  auto local__ = local ? _fbb.CreateString(local) : 0;
+  auto format__ = format ? _fbb.CreateString(format) : 0;
+  return Styles::CreateFontSource(
+      _fbb,
+      url__,
+      local__,
+      format__);
+}
+
+struct UnicodeRange FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef UnicodeRangeBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_START = 4,
+    VT_END = 6
+  };
+  uint32_t start() const {
+    return GetField<uint32_t>(VT_START, 0);
+  }
+  uint32_t end() const {
+    return GetField<uint32_t>(VT_END, 0);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<uint32_t>(verifier, VT_START, 4) &&
+           VerifyField<uint32_t>(verifier, VT_END, 4) &&
+           verifier.EndTable();
+  }
+};
+
+struct UnicodeRangeBuilder {
+  typedef UnicodeRange Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_start(uint32_t start) {
+    fbb_.AddElement<uint32_t>(UnicodeRange::VT_START, start, 0);
+  }
+  void add_end(uint32_t end) {
+    fbb_.AddElement<uint32_t>(UnicodeRange::VT_END, end, 0);
+  }
+  explicit UnicodeRangeBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<UnicodeRange> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<UnicodeRange>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<UnicodeRange> CreateUnicodeRange(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    uint32_t start = 0,
+    uint32_t end = 0) {
+  UnicodeRangeBuilder builder_(_fbb);
+  builder_.add_end(end);
+  builder_.add_start(start);
+  return builder_.Finish();
 }
 
 struct Font FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   typedef FontBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
     VT_FONT_FAMILY = 4,
-    VT_SRC = 6
+    VT_SRC = 6,
+    VT_FONT_WEIGHT = 8,
+    VT_SOURCES = 10,
+    VT_FONT_STYLE = 12,
+    VT_FONT_WEIGHT_MIN = 14,
+    VT_FONT_WEIGHT_MAX = 16,
+    VT_UNICODE_RANGE = 18,
+    VT_FONT_DISPLAY = 20
   };
   const ::flatbuffers::String *font_family() const {
     return GetPointer<const ::flatbuffers::String *>(VT_FONT_FAMILY);
@@ -1437,12 +2001,45 @@ struct Font FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   const ::flatbuffers::String *src() const {
     return GetPointer<const ::flatbuffers::String *>(VT_SRC);
   }
+  ::flatbuffers::Optional<uint8_t> font_weight() const {
+    return GetOptional<uint8_t, uint8_t>(VT_FONT_WEIGHT);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::FontSource>> *sources() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::FontSource>> *>(VT_SOURCES);
+  }
+  ::flatbuffers::Optional<uint8_t> font_style() const {
+    return GetOptional<uint8_t, uint8_t>(VT_FONT_STYLE);
+  }
+  uint16_t font_weight_min() const {
+    return GetField<uint16_t>(VT_FONT_WEIGHT_MIN, 0);
+  }
+  uint16_t font_weight_max() const {
+    return GetField<uint16_t>(VT_FONT_WEIGHT_MAX, 0);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::UnicodeRange>> *unicode_range() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::UnicodeRange>> *>(VT_UNICODE_RANGE);
+  }
+  const ::flatbuffers::String *font_display() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_FONT_DISPLAY);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyOffset(verifier, VT_FONT_FAMILY) &&
            verifier.VerifyString(font_family()) &&
            VerifyOffset(verifier, VT_SRC) &&
            verifier.VerifyString(src()) &&
+           VerifyField<uint8_t>(verifier, VT_FONT_WEIGHT, 1) &&
+           VerifyOffset(verifier, VT_SOURCES) &&
+           verifier.VerifyVector(sources()) &&
+           verifier.VerifyVectorOfTables(sources()) &&
+           VerifyField<uint8_t>(verifier, VT_FONT_STYLE, 1) &&
+           VerifyField<uint16_t>(verifier, VT_FONT_WEIGHT_MIN, 2) &&
+           VerifyField<uint16_t>(verifier, VT_FONT_WEIGHT_MAX, 2) &&
+           VerifyOffset(verifier, VT_UNICODE_RANGE) &&
+           verifier.VerifyVector(unicode_range()) &&
+           verifier.VerifyVectorOfTables(unicode_range()) &&
+           VerifyOffset(verifier, VT_FONT_DISPLAY) &&
+           verifier.VerifyString(font_display()) &&
            verifier.EndTable();
   }
 };
@@ -1457,6 +2054,27 @@ struct FontBuilder {
   void add_src(::flatbuffers::Offset<::flatbuffers::String> src) {
     fbb_.AddOffset(Font::VT_SRC, src);
   }
+  void add_font_weight(uint8_t font_weight) {
+    fbb_.AddElement<uint8_t>(Font::VT_FONT_WEIGHT, font_weight);
+  }
+  void add_sources(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::FontSource>>> sources) {
+    fbb_.AddOffset(Font::VT_SOURCES, sources);
+  }
+  void add_font_style(uint8_t font_style) {
+    fbb_.AddElement<uint8_t>(Font::VT_FONT_STYLE, font_style);
+  }
+  void add_font_weight_min(uint16_t font_weight_min) {
+    fbb_.AddElement<uint16_t>(Font::VT_FONT_WEIGHT_MIN, font_weight_min, 0);
+  }
+  void add_font_weight_max(uint16_t font_weight_max) {
+    fbb_.AddElement<uint16_t>(Font::VT_FONT_WEIGHT_MAX, font_weight_max, 0);
+  }
+  void add_unicode_range(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::UnicodeRange>>> unicode_range) {
+    fbb_.AddOffset(Font::VT_UNICODE_RANGE, unicode_range);
+  }
+  void add_font_display(::flatbuffers::Offset<::flatbuffers::String> font_display) {
+    fbb_.AddOffset(Font::VT_FONT_DISPLAY, font_display);
+  }
   explicit FontBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -1471,23 +2089,54 @@ struct FontBuilder {
 inline ::flatbuffers::Offset<Font> CreateFont(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     ::flatbuffers::Offset<::flatbuffers::String> font_family = 0,
-    ::flatbuffers::Offset<::flatbuffers::String> src = 0) {
+    ::flatbuffers::Offset<::flatbuffers::String> src = 0,
+    ::flatbuffers::Optional<uint8_t> font_weight = ::flatbuffers::nullopt,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::FontSource>>> sources = 0,
+    ::flatbuffers::Optional<uint8_t> font_style = ::flatbuffers::nullopt,
+    uint16_t font_weight_min = 0,
+    uint16_t font_weight_max = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::UnicodeRange>>> unicode_range = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> font_display = 0) {
   FontBuilder builder_(_fbb);
+  builder_.add_font_display(font_display);
+  builder_.add_unicode_range(unicode_range);
+  builder_.add_sources(sources);
   builder_.add_src(src);
   builder_.add_font_family(font_family);
+  builder_.add_font_weight_max(font_weight_max);
+  builder_.add_font_weight_min(font_weight_min);
+  if(font_style) { builder_.add_font_style(*font_style); }
+  if(font_weight) { builder_.add_font_weight(*font_weight); }
   return builder_.Finish();
 }
 
 inline ::flatbuffers::Offset<Font> CreateFontDirect(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     const char *font_family = nullptr,
-    const char *src = nullptr) {
+    const char *src = nullptr,
+    ::flatbuffers::Optional<uint8_t> font_weight = ::flatbuffers::nullopt,
+    const std::vector<::flatbuffers::Offset<Styles::FontSource>> *sources = nullptr,
+    ::flatbuffers::Optional<uint8_t> font_style = ::flatbuffers::nullopt,
+    uint16_t font_weight_min = 0,
+    uint16_t font_weight_max = 0,
+    const std::vector<::flatbuffers::Offset<Styles::UnicodeRange>> *unicode_range = nullptr,
+    const char *font_display = nullptr) {
   auto font_family__ = font_family ? _fbb.CreateString(font_family) : 0;
   auto src__ = src ? _fbb.CreateString(src) : 0;
+  auto sources__ = sources ? _fbb.CreateVector<::flatbuffers::Offset<Styles::FontSource>>(*sources) : 0;
+  auto unicode_range__ = unicode_range ? _fbb.CreateVector<::flatbuffers::Offset<Styles::UnicodeRange>>(*unicode_range) : 0;
+  auto font_display__ = font_display ? _fbb.CreateString(font_display) : 0;
   return Styles::CreateFont(
       _fbb,
       font_family__,
-      src__);
+      src__,
+      font_weight,
+      sources__,
+      font_style,
+      font_weight_min,
+      font_weight_max,
+      unicode_range__,
+      font_display__);
 }
 
 struct KeyframeAnimationPoint FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
@@ -1558,26 +2207,31 @@ struct KeyframeAnimation FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table
   typedef KeyframeAnimationBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
     VT_NAME = 4,
-    VT_MEDIA = 6,
-    VT_KEYFRAME_POINTS = 8
+    VT_MEDIA_V1 = 6,
+    VT_KEYFRAME_POINTS = 8,
+    VT_MEDIA = 10
   };
   const ::flatbuffers::String *name() const {
     return GetPointer<const ::flatbuffers::String *>(VT_NAME);
   }
-  uint8_t media() const {
-    return GetField<uint8_t>(VT_MEDIA, 0);
+  uint8_t media_v1() const {
+    return GetField<uint8_t>(VT_MEDIA_V1, 0);
   }
   const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>> *keyframe_points() const {
     return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>> *>(VT_KEYFRAME_POINTS);
   }
+  uint32_t media() const {
+    return GetField<uint32_t>(VT_MEDIA, 0);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyOffset(verifier, VT_NAME) &&
            verifier.VerifyString(name()) &&
-           VerifyField<uint8_t>(verifier, VT_MEDIA, 1) &&
+           VerifyField<uint8_t>(verifier, VT_MEDIA_V1, 1) &&
            VerifyOffset(verifier, VT_KEYFRAME_POINTS) &&
            verifier.VerifyVector(keyframe_points()) &&
            verifier.VerifyVectorOfTables(keyframe_points()) &&
+           VerifyField<uint32_t>(verifier, VT_MEDIA, 4) &&
            verifier.EndTable();
   }
 };
@@ -1589,12 +2243,15 @@ struct KeyframeAnimationBuilder {
   void add_name(::flatbuffers::Offset<::flatbuffers::String> name) {
     fbb_.AddOffset(KeyframeAnimation::VT_NAME, name);
   }
-  void add_media(uint8_t media) {
-    fbb_.AddElement<uint8_t>(KeyframeAnimation::VT_MEDIA, media, 0);
+  void add_media_v1(uint8_t media_v1) {
+    fbb_.AddElement<uint8_t>(KeyframeAnimation::VT_MEDIA_V1, media_v1, 0);
   }
   void add_keyframe_points(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>>> keyframe_points) {
     fbb_.AddOffset(KeyframeAnimation::VT_KEYFRAME_POINTS, keyframe_points);
   }
+  void add_media(uint32_t media) {
+    fbb_.AddElement<uint32_t>(KeyframeAnimation::VT_MEDIA, media, 0);
+  }
   explicit KeyframeAnimationBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -1609,27 +2266,31 @@ struct KeyframeAnimationBuilder {
 inline ::flatbuffers::Offset<KeyframeAnimation> CreateKeyframeAnimation(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     ::flatbuffers::Offset<::flatbuffers::String> name = 0,
-    uint8_t media = 0,
-    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>>> keyframe_points = 0) {
+    uint8_t media_v1 = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>>> keyframe_points = 0,
+    uint32_t media = 0) {
   KeyframeAnimationBuilder builder_(_fbb);
+  builder_.add_media(media);
   builder_.add_keyframe_points(keyframe_points);
   builder_.add_name(name);
-  builder_.add_media(media);
+  builder_.add_media_v1(media_v1);
   return builder_.Finish();
 }
 
 inline ::flatbuffers::Offset<KeyframeAnimation> CreateKeyframeAnimationDirect(
     ::flatbuffers::FlatBufferBuilder &_fbb,
     const char *name = nullptr,
-    uint8_t media = 0,
-    const std::vector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>> *keyframe_points = nullptr) {
+    uint8_t media_v1 = 0,
+    const std::vector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>> *keyframe_points = nullptr,
+    uint32_t media = 0) {
   auto name__ = name ? _fbb.CreateString(name) : 0;
   auto keyframe_points__ = keyframe_points ? _fbb.CreateVector<::flatbuffers::Offset<Styles::KeyframeAnimationPoint>>(*keyframe_points) : 0;
   return Styles::CreateKeyframeAnimation(
       _fbb,
       name__,
-      media,
-      keyframe_points__);
+      media_v1,
+      keyframe_points__,
+      media);
 }
 
 struct PrimitiveCondition FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
@@ -1914,21 +2575,26 @@ inline ::flatbuffers::Offset<Condition> CreateCondition(
 struct Media FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   typedef MediaBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
-    VT_ID = 4,
-    VT_CONDITIONS = 6
+    VT_ID_V1 = 4,
+    VT_CONDITIONS = 6,
+    VT_ID = 8
   };
-  uint8_t id() const {
-    return GetField<uint8_t>(VT_ID, 0);
+  uint8_t id_v1() const {
+    return GetField<uint8_t>(VT_ID_V1, 0);
   }
   const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>> *conditions() const {
     return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>> *>(VT_CONDITIONS);
   }
+  uint32_t id() const {
+    return GetField<uint32_t>(VT_ID, 0);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
-           VerifyField<uint8_t>(verifier, VT_ID, 1) &&
+           VerifyField<uint8_t>(verifier, VT_ID_V1, 1) &&
            VerifyOffset(verifier, VT_CONDITIONS) &&
            verifier.VerifyVector(conditions()) &&
            verifier.VerifyVectorOfTables(conditions()) &&
+           VerifyField<uint32_t>(verifier, VT_ID, 4) &&
            verifier.EndTable();
   }
 };
@@ -1937,12 +2603,15 @@ struct MediaBuilder {
   typedef Media Table;
   ::flatbuffers::FlatBufferBuilder &fbb_;
   ::flatbuffers::uoffset_t start_;
-  void add_id(uint8_t id) {
-    fbb_.AddElement<uint8_t>(Media::VT_ID, id, 0);
+  void add_id_v1(uint8_t id_v1) {
+    fbb_.AddElement<uint8_t>(Media::VT_ID_V1, id_v1, 0);
   }
   void add_conditions(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>>> conditions) {
     fbb_.AddOffset(Media::VT_CONDITIONS, conditions);
   }
+  void add_id(uint32_t id) {
+    fbb_.AddElement<uint32_t>(Media::VT_ID, id, 0);
+  }
   explicit MediaBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -1956,22 +2625,116 @@ struct MediaBuilder {
 
 inline ::flatbuffers::Offset<Media> CreateMedia(
     ::flatbuffers::FlatBufferBuilder &_fbb,
-    uint8_t id = 0,
-    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>>> conditions = 0) {
+    uint8_t id_v1 = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>>> conditions = 0,
+    uint32_t id = 0) {
   MediaBuilder builder_(_fbb);
-  builder_.add_conditions(conditions);
   builder_.add_id(id);
+  builder_.add_conditions(conditions);
+  builder_.add_id_v1(id_v1);
   return builder_.Finish();
 }
 
 inline ::flatbuffers::Offset<Media> CreateMediaDirect(
     ::flatbuffers::FlatBufferBuilder &_fbb,
-    uint8_t id = 0,
-    const std::vector<::flatbuffers::Offset<Styles::Condition>> *conditions = nullptr) {
+    uint8_t id_v1 = 0,
+    const std::vector<::flatbuffers::Offset<Styles::Condition>> *conditions = nullptr,
+    uint32_t id = 0) {
   auto conditions__ = conditions ? _fbb.CreateVector<::flatbuffers::Offset<Styles::Condition>>(*conditions) : 0;
   return Styles::CreateMedia(
+      _fbb,
+      id_v1,
+      conditions__,
+      id);
+}
+
+struct Container FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ContainerBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_ID = 4,
+    VT_NAME = 6,
+    VT_PARENT = 8,
+    VT_CONDITIONS = 10
+  };
+  uint32_t id() const {
+    return GetField<uint32_t>(VT_ID, 0);
+  }
+  const ::flatbuffers::String *name() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_NAME);
+  }
+  uint32_t parent() const {
+    return GetField<uint32_t>(VT_PARENT, 0);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>> *conditions() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>> *>(VT_CONDITIONS);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<uint32_t>(verifier, VT_ID, 4) &&
+           VerifyOffset(verifier, VT_NAME) &&
+           verifier.VerifyString(name()) &&
+           VerifyField<uint32_t>(verifier, VT_PARENT, 4) &&
+           VerifyOffset(verifier, VT_CONDITIONS) &&
+           verifier.VerifyVector(conditions()) &&
+           verifier.VerifyVectorOfTables(conditions()) &&
+           verifier.EndTable();
+  }
+};
+
+struct ContainerBuilder {
+  typedef Container Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_id(uint32_t id) {
+    fbb_.AddElement<uint32_t>(Container::VT_ID, id, 0);
+  }
+  void add_name(::flatbuffers::Offset<::flatbuffers::String> name) {
+    fbb_.AddOffset(Container::VT_NAME, name);
+  }
+  void add_parent(uint32_t parent) {
+    fbb_.AddElement<uint32_t>(Container::VT_PARENT, parent, 0);
+  }
+  void add_conditions(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>>> conditions) {
+    fbb_.AddOffset(Container::VT_CONDITIONS, conditions);
+  }
+  explicit ContainerBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<Container> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<Container>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<Container> CreateContainer(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    uint32_t id = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> name = 0,
+    uint32_t parent = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Condition>>> conditions = 0) {
+  ContainerBuilder builder_(_fbb);
+  builder_.add_conditions(conditions);
+  builder_.add_parent(parent);
+  builder_.add_name(name);
+  builder_.add_id(id);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<Container> CreateContainerDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    uint32_t id = 0,
+    const char *name = nullptr,
+    uint32_t parent = 0,
+    const std::vector<::flatbuffers::Offset<Styles::Condition>> *conditions = nullptr) {
+  auto name__ = name ? _fbb.CreateString(name) : 0;
+  auto conditions__ = conditions ? _fbb.CreateVector<::flatbuffers::Offset<Styles::Condition>>(*conditions) : 0;
+  return Styles::CreateContainer(
       _fbb,
       id,
+      name__,
+      parent,
       conditions__);
 }
 
@@ -1983,7 +2746,10 @@ struct StyleSheet FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
     VT_MEDIAS = 8,
     VT_STYLES = 10,
     VT_DESIGN_WIDTH = 12,
-    VT_ALLOW_INHERIT = 14
+    VT_ALLOW_INHERIT = 14,
+    VT_DESIGN_MODE = 16,
+    VT_FORMAT_VERSION = 18,
+    VT_CONTAINERS = 20
   };
   const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Font>> *fonts() const {
     return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Font>> *>(VT_FONTS);
@@ -2003,6 +2769,15 @@ struct StyleSheet FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   bool allow_inherit() const {
     return GetField<uint8_t>(VT_ALLOW_INHERIT, 0) != 0;
   }
+  const ::flatbuffers::String *design_mode() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_DESIGN_MODE);
+  }
+  uint16_t format_version() const {
+    return GetField<uint16_t>(VT_FORMAT_VERSION, 0);
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Container>> *containers() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<Styles::Container>> *>(VT_CONTAINERS);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyOffset(verifier, VT_FONTS) &&
@@ -2019,6 +2794,12 @@ struct StyleSheet FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
            verifier.VerifyVectorOfTables(styles()) &&
            VerifyField<uint16_t>(verifier, VT_DESIGN_WIDTH, 2) &&
            VerifyField<uint8_t>(verifier, VT_ALLOW_INHERIT, 1) &&
+           VerifyOffset(verifier, VT_DESIGN_MODE) &&
+           verifier.VerifyString(design_mode()) &&
+           VerifyField<uint16_t>(verifier, VT_FORMAT_VERSION, 2) &&
+           VerifyOffset(verifier, VT_CONTAINERS) &&
+           verifier.VerifyVector(containers()) &&
+           verifier.VerifyVectorOfTables(containers()) &&
            verifier.EndTable();
   }
 };
@@ -2045,6 +2826,15 @@ struct StyleSheetBuilder {
   void add_allow_inherit(bool allow_inherit) {
     fbb_.AddElement<uint8_t>(StyleSheet::VT_ALLOW_INHERIT, static_cast<uint8_t>(allow_inherit), 0);
   }
+  void add_design_mode(::flatbuffers::Offset<::flatbuffers::String> design_mode) {
+    fbb_.AddOffset(StyleSheet::VT_DESIGN_MODE, design_mode);
+  }
+  void add_format_version(uint16_t format_version) {
+    fbb_.AddElement<uint16_t>(StyleSheet::VT_FORMAT_VERSION, format_version, 0);
+  }
+  void add_containers(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Container>>> containers) {
+    fbb_.AddOffset(StyleSheet::VT_CONTAINERS, containers);
+  }
   explicit StyleSheetBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -2063,12 +2853,18 @@ inline ::flatbuffers::Offset<StyleSheet> CreateStyleSheet(
     ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Media>>> medias = 0,
     ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Style>>> styles = 0,
     uint16_t design_width = 0,
-    bool allow_inherit = false) {
+    bool allow_inherit = false,
+    ::flatbuffers::Offset<::flatbuffers::String> design_mode = 0,
+    uint16_t format_version = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<Styles::Container>>> containers = 0) {
   StyleSheetBuilder builder_(_fbb);
+  builder_.add_containers(containers);
+  builder_.add_design_mode(design_mode);
   builder_.add_styles(styles);
   builder_.add_medias(medias);
   builder_.add_keyframes(keyframes);
   builder_.add_fonts(fonts);
+  builder_.add_format_version(format_version);
   builder_.add_design_width(design_width);
   builder_.add_allow_inherit(allow_inherit);
   return builder_.Finish();
@@ -2081,11 +2877,16 @@ inline ::flatbuffers::Offset<StyleSheet> CreateStyleSheetDirect(
     const std::vector<::flatbuffers::Offset<Styles::Media>> *medias = nullptr,
     const std::vector<::flatbuffers::Offset<Styles::Style>> *styles = nullptr,
     uint16_t design_width = 0,
-    bool allow_inherit = false) {
+    bool allow_inherit = false,
+    const char *design_mode = nullptr,
+    uint16_t format_version = 0,
+    const std::vector<::flatbuffers::Offset<Styles::Container>> *containers = nullptr) {
   auto fonts__ = fonts ? _fbb.CreateVector<::flatbuffers::Offset<Styles::Font>>(*fonts) : 0;
   auto keyframes__ = keyframes ? _fbb.CreateVector<::flatbuffers::Offset<Styles::KeyframeAnimation>>(*keyframes) : 0;
   auto medias__ = medias ? _fbb.CreateVector<::flatbuffers::Offset<Styles::Media>>(*medias) : 0;
   auto styles__ = styles ? _fbb.CreateVector<::flatbuffers::Offset<Styles::Style>>(*styles) : 0;
+  auto design_mode__ = design_mode ? _fbb.CreateString(design_mode) : 0;
+  auto containers__ = containers ? _fbb.CreateVector<::flatbuffers::Offset<Styles::Container>>(*containers) : 0;
   return Styles::CreateStyleSheet(
       _fbb,
       fonts__,
@@ -2093,7 +2894,10 @@ inline ::flatbuffers::Offset<StyleSheet> CreateStyleSheetDirect(
       medias__,
       styles__,
       design_width,
-      allow_inherit);
+      allow_inherit,
+      design_mode__,
+      format_version,
+      containers__);
 }
 
 inline bool VerifyValue(::flatbuffers::Verifier &verifier, const void *obj, Value type) {
@@ -2198,26 +3002,40 @@ inline const Styles::StyleSheet *GetSizePrefixedStyleSheet(const void *buf) {
   return ::flatbuffers::GetSizePrefixedRoot<Styles::StyleSheet>(buf);
 }
 
+inline const char *StyleSheetIdentifier() {
+  return "TCSS";
+}
+
+inline bool StyleSheetBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, StyleSheetIdentifier());
+}
+
+inline bool SizePrefixedStyleSheetBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, StyleSheetIdentifier(), true);
+}
+
 inline bool VerifyStyleSheetBuffer(
     ::flatbuffers::Verifier &verifier) {
-  return verifier.VerifyBuffer<Styles::StyleSheet>(nullptr);
+  return verifier.VerifyBuffer<Styles::StyleSheet>(StyleSheetIdentifier());
 }
 
 inline bool VerifySizePrefixedStyleSheetBuffer(
     ::flatbuffers::Verifier &verifier) {
-  return verifier.VerifySizePrefixedBuffer<Styles::StyleSheet>(nullptr);
+  return verifier.VerifySizePrefixedBuffer<Styles::StyleSheet>(StyleSheetIdentifier());
 }
 
 inline void FinishStyleSheetBuffer(
     ::flatbuffers::FlatBufferBuilder &fbb,
     ::flatbuffers::Offset<Styles::StyleSheet> root) {
-  fbb.Finish(root);
+  fbb.Finish(root, StyleSheetIdentifier());
 }
 
 inline void FinishSizePrefixedStyleSheetBuffer(
     ::flatbuffers::FlatBufferBuilder &fbb,
     ::flatbuffers::Offset<Styles::StyleSheet> root) {
-  fbb.FinishSizePrefixed(root);
+  fbb.FinishSizePrefixed(root, StyleSheetIdentifier());
 }
 
 }  // namespace Styles
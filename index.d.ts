@@ -12,9 +12,43 @@ export interface ParseOptions {
   output?: OutputOptions
   allowInherit?: boolean
   designMode?: string
+  strict?: boolean
+  sourceMap?: boolean
+  formatVersion?: number
+  importRoot?: string
+}
+export interface DecodeOptions {
+  propertyNames?: boolean
+}
+export interface StyleInput {
+  filename: string
+  code: string
+}
+export interface ParseError {
+  message: string
+  filename?: string
+  line: number
+  column: number
+  index: number
+}
+export interface ParseWarning {
+  message: string
+  selector: string
+  property: string
+  value: string
+  reason: string
+  filename?: string
+  line: number
+  column: number
+  index: number
 }
 export interface ParseResult {
   code?: string
   buffer?: Buffer
+  errors: Array<ParseError>
+  warnings: Array<ParseWarning>
+  sourceMap?: string
 }
-export declare function parse(styles: Array<string>, options: ParseOptions): ParseResult
+export declare function parse(styles: Array<string>, options: ParseOptions, resolve?: (...args: any[]) => any | undefined | null): ParseResult
+export declare function parseFiles(inputs: Array<StyleInput>, options: ParseOptions, resolve?: (...args: any[]) => any | undefined | null): ParseResult
+export declare function decode(buffer: Buffer, options?: DecodeOptions | undefined | null): string
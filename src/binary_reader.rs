@@ -0,0 +1,452 @@
+use serde_json::{json, Map, Value};
+
+use crate::constants::{FORMAT_VERSION, FORMAT_VERSION_V1, FORMAT_VERSION_V2};
+use crate::style_propetries::style_property_type::css_property_type_to_string;
+use crate::stylesheet_generated::styles;
+
+// 与 expr_to_json 的规则一致，小数部分为 0 的数字作为整数输出
+fn number_to_json(value: f64) -> Value {
+  if value.fract() == 0.0 {
+    Value::Number(serde_json::Number::from(value as i64))
+  } else {
+    serde_json::Number::from_f64(value).map(Value::Number).unwrap_or(Value::Null)
+  }
+}
+
+// 读取带有 Value union 的表（DeclarationTuple、KeyValue、PrimitiveCondition）中的值
+macro_rules! decode_value {
+  ($table:expr) => {{
+    let table = &$table;
+    match table.value_type() {
+      styles::Value::String => table
+        .value_as_string()
+        .and_then(|value| value.value())
+        .map(|value| Value::String(value.to_string())),
+      styles::Value::Integer => table.value_as_integer().map(|value| json!(value.value())),
+      styles::Value::Double => table.value_as_double().map(|value| number_to_json(value.value())),
+      styles::Value::Boolean => table.value_as_boolean().map(|value| Value::Bool(value.value())),
+      styles::Value::StringArray => table.value_as_string_array().map(|value| {
+        Value::Array(
+          value
+            .values()
+            .map(|values| values.iter().map(|value| Value::String(value.to_string())).collect())
+            .unwrap_or_default(),
+        )
+      }),
+      styles::Value::IntegerArray => table.value_as_integer_array().map(|value| {
+        Value::Array(
+          value
+            .values()
+            .map(|values| values.iter().map(|value| json!(value)).collect())
+            .unwrap_or_default(),
+        )
+      }),
+      styles::Value::DoubleArray => table.value_as_double_array().map(|value| {
+        Value::Array(
+          value
+            .values()
+            .map(|values| values.iter().map(number_to_json).collect())
+            .unwrap_or_default(),
+        )
+      }),
+      styles::Value::Object => table.value_as_object().map(|value| {
+        let mut map = Map::new();
+        for field in value.fields().iter().flatten() {
+          map.insert(field.key().unwrap_or_default().to_string(), decode_key_value(field));
+        }
+        Value::Object(map)
+      }),
+      styles::Value::ObjectArray => table.value_as_object_array().map(|value| {
+        Value::Array(value.values().iter().flatten().map(decode_key_value).collect())
+      }),
+      styles::Value::IntegereArrayArray => table.value_as_integere_array_array().map(|value| {
+        Value::Array(
+          value
+            .values()
+            .iter()
+            .flatten()
+            .map(|item| {
+              Value::Array(
+                item
+                  .values()
+                  .map(|values| values.iter().map(|value| json!(value)).collect())
+                  .unwrap_or_default(),
+              )
+            })
+            .collect(),
+        )
+      }),
+      styles::Value::DoubleArrayArray => table.value_as_double_array_array().map(|value| {
+        Value::Array(
+          value
+            .values()
+            .iter()
+            .flatten()
+            .map(|item| {
+              Value::Array(
+                item
+                  .values()
+                  .map(|values| values.iter().map(number_to_json).collect())
+                  .unwrap_or_default(),
+              )
+            })
+            .collect(),
+        )
+      }),
+      _ => None,
+    }
+    .unwrap_or(Value::Null)
+  }};
+}
+
+// Object、ObjectArray 中的值，单独定义函数以便递归
+fn decode_key_value(key_value: styles::KeyValue) -> Value {
+  decode_value!(key_value)
+}
+
+fn decode_declarations<'a>(
+  declarations: Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<styles::DeclarationTuple<'a>>>>,
+  property_names: bool,
+  legacy: bool,
+) -> Value {
+  Value::Array(
+    declarations
+      .iter()
+      .flatten()
+      .map(|declaration| {
+        let property_id = if legacy {
+          declaration.property_id_v1() as u32
+        } else {
+          declaration.property_id() as u32
+        };
+        let property = match css_property_type_to_string(property_id) {
+          Some(name) if property_names => json!(name),
+          _ => json!(property_id),
+        };
+        let mut tuple = vec![property, decode_value!(declaration)];
+        if declaration.flag() > 0 {
+          tuple.push(json!(declaration.flag()));
+        }
+        Value::Array(tuple)
+      })
+      .collect(),
+  )
+}
+
+fn decode_condition(condition: styles::Condition) -> Value {
+  let value = match condition.value_type() {
+    styles::ConditionValue::PrimitiveCondition => condition
+      .value_as_primitive_condition()
+      .map(|primitive| {
+        let mut params = vec![json!(primitive.feature()), json!(primitive.operator())];
+        if primitive.value_type() != styles::Value::NONE {
+          params.push(decode_value!(primitive));
+        }
+        Value::Array(params)
+      }),
+    styles::ConditionValue::CompoundCondition => condition
+      .value_as_compound_condition()
+      .map(|compound| Value::Array(compound.conditions().iter().flatten().map(decode_condition).collect())),
+    _ => None,
+  };
+  json!([condition.type_(), value.unwrap_or(Value::Null)])
+}
+
+fn decode_pseudo_key<'a>(
+  pseudo_key: flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<styles::PseudoKey<'a>>>,
+  format_version: u16,
+) -> Value {
+  Value::Array(
+    pseudo_key
+      .iter()
+      .map(|key| {
+        if key.is_int() && format_version <= FORMAT_VERSION_V2 {
+          json!(key.integer_value_v1())
+        } else if key.is_int() {
+          json!(key.integer_value())
+        } else {
+          json!(key.bool_value())
+        }
+      })
+      .collect(),
+  )
+}
+
+// 与 CompoundSelector::to_expr 一致，未设置的字段不输出
+fn decode_compound_selector(compound: styles::CompoundSelector) -> Value {
+  let mut map = Map::new();
+  if let Some(tag) = compound.tag() {
+    map.insert("tag".to_string(), json!(tag));
+  }
+  if let Some(id) = compound.id() {
+    map.insert("id".to_string(), json!(id));
+  }
+  if let Some(classes) = compound.classes() {
+    map.insert("classes".to_string(), json!(classes.iter().collect::<Vec<&str>>()));
+  }
+  if let Some(attributes) = compound.attributes() {
+    let attributes = attributes
+      .iter()
+      .map(|attribute| {
+        let mut attribute_map = Map::new();
+        attribute_map.insert("name".to_string(), json!(attribute.name().unwrap_or_default()));
+        attribute_map.insert("operator".to_string(), json!(attribute.operator()));
+        if let Some(value) = attribute.value() {
+          attribute_map.insert("value".to_string(), json!(value));
+        }
+        if attribute.case_insensitive() {
+          attribute_map.insert("case_insensitive".to_string(), json!(true));
+        }
+        Value::Object(attribute_map)
+      })
+      .collect::<Vec<Value>>();
+    map.insert("attributes".to_string(), Value::Array(attributes));
+  }
+  if let Some(pseudos) = compound.pseudos() {
+    let pseudos = pseudos
+      .iter()
+      .map(|pseudo| {
+        let mut pseudo_map = Map::new();
+        pseudo_map.insert("pseudo".to_string(), json!(pseudo.pseudo()));
+        if let Some(pseudo_key) = pseudo.pseudo_key() {
+          pseudo_map.insert("pseudo_key".to_string(), decode_pseudo_key(pseudo_key, FORMAT_VERSION));
+        }
+        if let Some(pseudo_val) = pseudo.pseudo_val() {
+          pseudo_map.insert("pseudo_val".to_string(), json!(pseudo_val));
+        }
+        Value::Object(pseudo_map)
+      })
+      .collect::<Vec<Value>>();
+    map.insert("pseudos".to_string(), Value::Array(pseudos));
+  }
+  if let Some(negations) = compound.negations() {
+    map.insert(
+      "negations".to_string(),
+      Value::Array(negations.iter().map(decode_compound_selector).collect()),
+    );
+  }
+  Value::Object(map)
+}
+
+fn decode_style(style: styles::Style, property_names: bool, format_version: u16) -> Value {
+  let legacy = format_version == FORMAT_VERSION_V1;
+  let mut map = Map::new();
+  let media = if legacy { style.media_v1() as u32 } else { style.media() };
+  map.insert("media".to_string(), json!(media));
+  map.insert(
+    "selector".to_string(),
+    Value::Array(
+      style
+        .selector()
+        .iter()
+        .flatten()
+        .map(|selector| {
+          if let Some(compound) = selector.compound() {
+            decode_compound_selector(compound)
+          } else if selector.is_string() {
+            json!(selector.string_value().unwrap_or_default())
+          } else {
+            json!(selector.integer_value())
+          }
+        })
+        .collect(),
+    ),
+  );
+  map.insert(
+    "declarations".to_string(),
+    decode_declarations(style.declarations(), property_names, legacy),
+  );
+  map.insert("specificity".to_string(), json!(style.specificity()));
+  map.insert("order".to_string(), json!(style.order()));
+  map.insert("layer".to_string(), json!(style.layer()));
+  map.insert("important_layer".to_string(), json!(style.important_layer()));
+  if style.container() > 0 {
+    map.insert("container".to_string(), json!(style.container()));
+  }
+  if let Some(variables) = style.variables() {
+    let mut variables_map = Map::new();
+    for variable in variables.iter() {
+      variables_map.insert(
+        variable.key().unwrap_or_default().to_string(),
+        json!(variable.value().unwrap_or_default()),
+      );
+    }
+    map.insert("variables".to_string(), Value::Object(variables_map));
+  }
+  if let Some(pseudo_val) = style.pseudo_val() {
+    map.insert("pseudo_val".to_string(), json!(pseudo_val));
+  }
+  if let Some(pseudo_key) = style.pseudo_key() {
+    map.insert("pseudo_key".to_string(), decode_pseudo_key(pseudo_key, format_version));
+  }
+  if style.pseudo() > 0 {
+    map.insert("pseudo".to_string(), json!(style.pseudo()));
+  }
+  if style.has_env() {
+    map.insert("has_env".to_string(), json!(true));
+  }
+  Value::Object(map)
+}
+
+// 校验并读取二进制样式，输出与 JsonWriter::to_json 相同结构的 JSON，property_names 为 true 时将属性 id 转换为属性名
+pub fn decode_stylesheet(buffer: &[u8], property_names: bool) -> Result<String, String> {
+  // 加入文件标识和版本信息之前生成的二进制没有文件标识，_v1 字段的位置未变，按版本 1 读取
+  let has_identifier = buffer.len() >= flatbuffers::SIZE_UOFFSET + flatbuffers::FILE_IDENTIFIER_LENGTH
+    && styles::style_sheet_buffer_has_identifier(buffer);
+  let style_sheet = styles::root_as_style_sheet(buffer).map_err(|error| {
+    if has_identifier {
+      error.to_string()
+    } else {
+      format!(
+        "missing file identifier \"{}\" and not a version 1 stylesheet binary: {}",
+        styles::STYLE_SHEET_IDENTIFIER, error
+      )
+    }
+  })?;
+  let format_version = if has_identifier {
+    style_sheet.format_version()
+  } else {
+    FORMAT_VERSION_V1
+  };
+  if !(FORMAT_VERSION_V1..=FORMAT_VERSION).contains(&format_version) {
+    return Err(format!(
+      "unsupported format version {}, expected {} to {}",
+      format_version, FORMAT_VERSION_V1, FORMAT_VERSION
+    ));
+  }
+  let legacy = format_version == FORMAT_VERSION_V1;
+
+  let styles = style_sheet
+    .styles()
+    .iter()
+    .flatten()
+    .map(|style| decode_style(style, property_names, format_version))
+    .collect::<Vec<Value>>();
+
+  let keyframes = style_sheet
+    .keyframes()
+    .iter()
+    .flatten()
+    .map(|keyframe| {
+      json!({
+        "name": keyframe.name().unwrap_or_default(),
+        "media": if legacy { keyframe.media_v1() as u32 } else { keyframe.media() },
+        "keyframe": keyframe
+          .keyframe_points()
+          .iter()
+          .flatten()
+          .map(|point| {
+            json!({
+              "percent": number_to_json(point.percentage() as f64),
+              "event": decode_declarations(point.declarations(), property_names, legacy),
+            })
+          })
+          .collect::<Vec<Value>>(),
+      })
+    })
+    .collect::<Vec<Value>>();
+
+  let medias = style_sheet
+    .medias()
+    .iter()
+    .flatten()
+    .map(|media| {
+      json!({
+        "id": if legacy { media.id_v1() as u32 } else { media.id() },
+        "conditions": media.conditions().iter().flatten().map(decode_condition).collect::<Vec<Value>>(),
+      })
+    })
+    .collect::<Vec<Value>>();
+
+  let fonts = style_sheet
+    .fonts()
+    .iter()
+    .flatten()
+    .map(|font| {
+      let mut font_json = json!({
+        "fontFamily": font.font_family().unwrap_or_default(),
+        "src": font.src().unwrap_or_default(),
+      });
+      let font_map = font_json.as_object_mut().unwrap();
+      if let Some(sources) = font.sources() {
+        let sources = sources
+          .iter()
+          .map(|source| {
+            let mut source_json = Map::new();
+            if let Some(url) = source.url() {
+              source_json.insert("url".to_string(), json!(url));
+            }
+            if let Some(local) = source.local() {
+              source_json.insert("local".to_string(), json!(local));
+            }
+            if let Some(format) = source.format() {
+              source_json.insert("format".to_string(), json!(format));
+            }
+            Value::Object(source_json)
+          })
+          .collect::<Vec<Value>>();
+        font_map.insert("sources".to_string(), json!(sources));
+      }
+      if let Some(font_style) = font.font_style() {
+        font_map.insert("fontStyle".to_string(), json!(font_style));
+      }
+      if let Some(font_weight) = font.font_weight() {
+        font_map.insert("fontWeight".to_string(), json!(font_weight));
+      }
+      if font.font_weight_max() > 0 {
+        font_map.insert("fontWeightRange".to_string(), json!([font.font_weight_min(), font.font_weight_max()]));
+      }
+      if let Some(unicode_range) = font.unicode_range() {
+        let unicode_range = unicode_range
+          .iter()
+          .map(|range| json!([range.start(), range.end()]))
+          .collect::<Vec<Value>>();
+        font_map.insert("unicodeRange".to_string(), json!(unicode_range));
+      }
+      if let Some(font_display) = font.font_display() {
+        font_map.insert("fontDisplay".to_string(), json!(font_display));
+      }
+      font_json
+    })
+    .collect::<Vec<Value>>();
+
+  let mut json_value = json!({
+    "styles": styles,
+    "keyframes": keyframes,
+    "medias": medias,
+    "fonts": fonts,
+  });
+  // 二进制中无法区分默认值和未设置，默认值不输出
+  let map = json_value.as_object_mut().unwrap();
+  if style_sheet.design_width() > 0 {
+    map.insert("design_width".to_string(), json!(style_sheet.design_width()));
+  }
+  if style_sheet.allow_inherit() {
+    map.insert("allow_inherit".to_string(), json!(true));
+  }
+  if let Some(design_mode) = style_sheet.design_mode() {
+    map.insert("design_mode".to_string(), json!(design_mode));
+  }
+  if let Some(containers) = style_sheet.containers() {
+    let containers = containers
+      .iter()
+      .map(|container| {
+        let mut container_json = json!({
+          "id": container.id(),
+          "conditions": container.conditions().iter().flatten().map(decode_condition).collect::<Vec<Value>>(),
+        });
+        let container_map = container_json.as_object_mut().unwrap();
+        if let Some(name) = container.name() {
+          container_map.insert("name".to_string(), json!(name));
+        }
+        if container.parent() > 0 {
+          container_map.insert("parent".to_string(), json!(container.parent()));
+        }
+        container_json
+      })
+      .collect::<Vec<Value>>();
+    map.insert("containers".to_string(), json!(containers));
+  }
+
+  serde_json::to_string(&json_value).map_err(|error| error.to_string())
+}
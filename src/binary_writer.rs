@@ -0,0 +1,735 @@
+use std::collections::BTreeMap;
+
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+use indexmap::IndexMap;
+use swc_core::ecma::ast::*;
+
+use crate::json_writer::WriterOptions;
+use crate::style_parser::{FontFaceItem, FontSource, KeyFrameItem, RuleItem};
+use crate::style_propetries::style_container::StyleContainer;
+use crate::style_propetries::style_media::{StyleMedia, StyleMediaCondType, StyleMediaCondition, StyleMediaFeatureID};
+use crate::style_propetries::style_property_type::CSS_PROPERTY_NAMES;
+use crate::style_propetries::style_value_type::StyleValueType;
+use crate::style_propetries::unit::Platform;
+use crate::stylesheet_generated::styles;
+use crate::visitor::collect_style_values;
+use crate::{constants::{Pseudo, SelectorType, FORMAT_VERSION, FORMAT_VERSION_V1, FORMAT_VERSION_V2}, utils};
+
+type FlatbufferValue = (styles::Value, WIPOffset<UnionWIPOffset>);
+
+// 属性 id 超出格式中的整数宽度时编译报错，版本 1 的 property_id_v1 为 uint8，版本 2 起的 property_id 为 uint16
+const _: () = {
+  let mut index = 0;
+  while index < CSS_PROPERTY_NAMES.len() {
+    assert!(
+      CSS_PROPERTY_NAMES[index].0 as u32 <= u8::MAX as u32,
+      "property ids no longer fit in the version 1 binary format"
+    );
+    index += 1;
+  }
+};
+
+// 直接将解析结果写入 FlatBuffer，输出结构与 JsonWriter + convert_json_to_flatbuffer 一致
+pub struct BinaryWriter {
+  styles: Vec<RuleItem>,
+  keyframes: IndexMap<(u32, String), Vec<KeyFrameItem>>,
+  medias: Vec<StyleMedia>,
+  containers: Vec<StyleContainer>,
+  fonts: Vec<FontFaceItem>,
+  design_width: Option<i32>,
+  allow_inherit: Option<bool>,
+  design_mode: Option<String>,
+  format_version: u16,
+}
+
+impl BinaryWriter {
+  pub fn new(
+    styles: Vec<RuleItem>,
+    keyframes: IndexMap<(u32, String), Vec<KeyFrameItem>>,
+    medias: Vec<StyleMedia>,
+    containers: Vec<StyleContainer>,
+    fonts: Vec<FontFaceItem>,
+    options: WriterOptions,
+  ) -> Self {
+    Self {
+      styles,
+      keyframes,
+      medias,
+      containers,
+      fonts,
+      design_width: options.design_width,
+      allow_inherit: options.allow_inherit,
+      design_mode: options.design_mode,
+      format_version: options.format_version.unwrap_or(FORMAT_VERSION),
+    }
+  }
+
+  pub fn to_binary(&self) -> Result<Vec<u8>, String> {
+    if !(FORMAT_VERSION_V1..=FORMAT_VERSION).contains(&self.format_version) {
+      return Err(format!(
+        "unsupported format version {}, expected {} to {}",
+        self.format_version, FORMAT_VERSION_V1, FORMAT_VERSION
+      ));
+    }
+    let legacy = self.format_version == FORMAT_VERSION_V1;
+    let legacy_v2 = self.format_version <= FORMAT_VERSION_V2;
+    let mut builder = FlatBufferBuilder::new();
+
+    let mut fonts = vec![];
+    for font in self.fonts.iter() {
+      fonts.push(create_font(&mut builder, font));
+    }
+    let fonts = builder.create_vector(&fonts);
+
+    let mut keyframes = vec![];
+    for ((media_index, name), keyframe) in self.keyframes.iter() {
+      keyframes.push(create_keyframe_animation(&mut builder, *media_index, name, keyframe, legacy)?);
+    }
+    let keyframes = builder.create_vector(&keyframes);
+
+    let mut medias = vec![];
+    for media in self.medias.iter() {
+      medias.push(create_media(&mut builder, media, legacy, legacy_v2)?);
+    }
+    let medias = builder.create_vector(&medias);
+
+    // 与 JSON 输出一致，没有 @container 时不写入 containers
+    let containers = if self.containers.is_empty() {
+      None
+    } else {
+      let mut containers = vec![];
+      for container in self.containers.iter() {
+        containers.push(create_container(&mut builder, container)?);
+      }
+      Some(builder.create_vector(&containers))
+    };
+
+    let mut styles = vec![];
+    for rule_item in self.styles.iter() {
+      styles.push(create_style(&mut builder, rule_item, legacy, legacy_v2)?);
+    }
+    let styles = builder.create_vector(&styles);
+
+    let design_mode = self
+      .design_mode
+      .as_ref()
+      .map(|design_mode| builder.create_string(design_mode));
+
+    let stylesheet = styles::StyleSheet::create(&mut builder, &styles::StyleSheetArgs {
+      fonts: Some(fonts),
+      keyframes: Some(keyframes),
+      medias: Some(medias),
+      styles: Some(styles),
+      // 与 JSON 输出保持一致，负数视为未设置
+      design_width: self.design_width.filter(|design_width| *design_width >= 0).unwrap_or(0) as u16,
+      allow_inherit: self.allow_inherit.unwrap_or(false),
+      design_mode,
+      format_version: self.format_version,
+      containers,
+    });
+
+    styles::finish_style_sheet_buffer(&mut builder, stylesheet);
+    Ok(builder.finished_data().to_vec())
+  }
+}
+
+fn create_font<'a>(builder: &mut FlatBufferBuilder<'a>, font: &FontFaceItem) -> WIPOffset<styles::Font<'a>> {
+  let font_family = builder.create_string(&font.font_family);
+  let src = builder.create_string(&font.src);
+  let sources = font
+    .sources
+    .iter()
+    .map(|source| {
+      let (url, local, format) = match source {
+        FontSource::Url { url, format } => (Some(url.as_str()), None, format.as_deref()),
+        FontSource::Local(local) => (None, Some(local.as_str()), None),
+      };
+      let url = url.map(|url| builder.create_string(url));
+      let local = local.map(|local| builder.create_string(local));
+      let format = format.map(|format| builder.create_string(format));
+      styles::FontSource::create(builder, &styles::FontSourceArgs { url, local, format })
+    })
+    .collect::<Vec<_>>();
+  let sources = builder.create_vector(&sources);
+  let unicode_range = if font.unicode_range.is_empty() {
+    None
+  } else {
+    let unicode_range = font
+      .unicode_range
+      .iter()
+      .map(|(start, end)| styles::UnicodeRange::create(builder, &styles::UnicodeRangeArgs { start: *start, end: *end }))
+      .collect::<Vec<_>>();
+    Some(builder.create_vector(&unicode_range))
+  };
+  let font_display = font
+    .font_display
+    .as_ref()
+    .map(|font_display| builder.create_string(font_display));
+  let (font_weight_min, font_weight_max) = font.font_weight_range.unwrap_or((0, 0));
+  styles::Font::create(builder, &styles::FontArgs {
+    font_family: Some(font_family),
+    src: Some(src),
+    font_weight: font.font_weight.map(|font_weight| font_weight as u8),
+    sources: Some(sources),
+    font_style: font.font_style.map(|font_style| font_style as u8),
+    font_weight_min,
+    font_weight_max,
+    unicode_range,
+    font_display,
+  })
+}
+
+fn create_keyframe_animation<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  media_index: u32,
+  name: &str,
+  keyframe: &[KeyFrameItem],
+  legacy: bool,
+) -> Result<WIPOffset<styles::KeyframeAnimation<'a>>, String> {
+  let (media_v1, media) = media_id(media_index, legacy)?;
+  let name = builder.create_string(name);
+  let mut keyframe_points = vec![];
+  for keyframe_item in keyframe.iter() {
+    let declarations = create_declarations(builder, keyframe_item.declarations.clone(), vec![], legacy)?;
+    keyframe_points.push(styles::KeyframeAnimationPoint::create(builder, &styles::KeyframeAnimationPointArgs {
+      percentage: keyframe_item.percentage,
+      declarations: Some(declarations),
+    }));
+  }
+  let keyframe_points = builder.create_vector(&keyframe_points);
+  Ok(styles::KeyframeAnimation::create(builder, &styles::KeyframeAnimationArgs {
+    name: Some(name),
+    media_v1,
+    keyframe_points: Some(keyframe_points),
+    media,
+  }))
+}
+
+fn create_media<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  media: &StyleMedia,
+  legacy: bool,
+  legacy_v2: bool,
+) -> Result<WIPOffset<styles::Media<'a>>, String> {
+  let (id_v1, id) = media_id(media.media_id, legacy)?;
+  if legacy_v2 && media.conditions.iter().any(requires_current_format) {
+    return Err(format!(
+      "media {} with a media type or `or` condition requires format version {}",
+      media.media_id, FORMAT_VERSION
+    ));
+  }
+  let mut conditions = vec![];
+  for condition in media.conditions.iter() {
+    conditions.push(create_condition(builder, media, condition)?);
+  }
+  let conditions = builder.create_vector(&conditions);
+  Ok(styles::Media::create(builder, &styles::MediaArgs {
+    id_v1,
+    conditions: Some(conditions),
+    id,
+  }))
+}
+
+// 版本 1、2 的运行时不认识媒体类型，并且将多个条件视为 and，无法表示媒体类型和逗号分隔的查询列表
+fn requires_current_format(condition: &StyleMediaCondition) -> bool {
+  match condition {
+    StyleMediaCondition::Feature(feature) => feature.feature_id == StyleMediaFeatureID::MediaType,
+    StyleMediaCondition::Operation { operation, conditions } => {
+      *operation == StyleMediaCondType::OR || conditions.iter().any(requires_current_format)
+    }
+  }
+}
+
+fn create_container<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  container: &StyleContainer,
+) -> Result<WIPOffset<styles::Container<'a>>, String> {
+  let media = container.as_media();
+  let name = container.name.as_ref().map(|name| builder.create_string(name));
+  let mut conditions = vec![];
+  for condition in container.conditions.iter() {
+    conditions.push(create_condition(builder, &media, condition)?);
+  }
+  let conditions = builder.create_vector(&conditions);
+  Ok(styles::Container::create(builder, &styles::ContainerArgs {
+    id: container.container_id,
+    name,
+    parent: container.parent,
+    conditions: Some(conditions),
+  }))
+}
+
+fn create_condition<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  media: &StyleMedia,
+  condition: &StyleMediaCondition,
+) -> Result<WIPOffset<styles::Condition<'a>>, String> {
+  match condition {
+    StyleMediaCondition::Feature(feature) => {
+      let value = match media.value_to_expr(feature.value.clone()) {
+        Some(value) => Some(create_value(builder, &value.expr)?),
+        None => None,
+      };
+      let primitive = styles::PrimitiveCondition::create(builder, &styles::PrimitiveConditionArgs {
+        feature: feature.feature_id.clone() as u8,
+        operator: feature.op.clone() as u8,
+        value_type: value.map(|(value_type, _)| value_type).unwrap_or(styles::Value::NONE),
+        value: value.map(|(_, value)| value),
+      });
+      Ok(styles::Condition::create(builder, &styles::ConditionArgs {
+        type_: StyleMediaCondType::None as u8,
+        value_type: styles::ConditionValue::PrimitiveCondition,
+        value: Some(primitive.as_union_value()),
+      }))
+    }
+    StyleMediaCondition::Operation { operation, conditions } => {
+      let mut compound_conditions = vec![];
+      for condition in conditions.iter() {
+        compound_conditions.push(create_condition(builder, media, condition)?);
+      }
+      let compound_conditions = builder.create_vector(&compound_conditions);
+      let compound = styles::CompoundCondition::create(builder, &styles::CompoundConditionArgs {
+        conditions: Some(compound_conditions),
+      });
+      Ok(styles::Condition::create(builder, &styles::ConditionArgs {
+        type_: operation.clone() as u8,
+        value_type: styles::ConditionValue::CompoundCondition,
+        value: Some(compound.as_union_value()),
+      }))
+    }
+  }
+}
+
+fn create_style<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  rule_item: &RuleItem,
+  legacy: bool,
+  legacy_v2: bool,
+) -> Result<WIPOffset<styles::Style<'a>>, String> {
+  let (media_v1, media) = media_id(rule_item.media, legacy)?;
+  // 版本 1、2 的运行时会忽略 layer，层中的样式无法按层的优先级生效，不在 @layer 中的样式 important_layer 为 0
+  if legacy_v2 && rule_item.important_layer > 0 {
+    return Err(format!(
+      "@layer style `{}` requires format version {}",
+      rule_item.selector.selector, FORMAT_VERSION
+    ));
+  }
+  // 版本 1、2 的运行时会忽略 container，@container 中的样式会无条件生效
+  if legacy_v2 && rule_item.container > 0 {
+    return Err(format!(
+      "@container style `{}` requires format version {}",
+      rule_item.selector.selector, FORMAT_VERSION
+    ));
+  }
+  let mut selector = vec![];
+  for (index, t_selector) in rule_item.selector.nesting_selector.iter().enumerate() {
+    match t_selector {
+      utils::TSelector::String(value) => selector.push(create_string_selector(builder, value)),
+      utils::TSelector::Selector(selector_type) => selector.push(styles::Selector::create(builder, &styles::SelectorArgs {
+        integer_value: selector_type.to_f64() as u8,
+        ..Default::default()
+      })),
+      utils::TSelector::Compound(compound) if legacy_v2 => {
+        if !compound.has_legacy_form(index == 0) {
+          return Err(format!(
+            "attribute, :not(), non-subject or multiple pseudo selector `{}` requires format version {}",
+            rule_item.selector.selector, FORMAT_VERSION
+          ));
+        }
+        for (index, value) in compound.to_legacy_strings().iter().enumerate() {
+          if index > 0 {
+            selector.push(styles::Selector::create(builder, &styles::SelectorArgs {
+              integer_value: SelectorType::Multiple.to_f64() as u8,
+              ..Default::default()
+            }));
+          }
+          selector.push(create_string_selector(builder, value));
+        }
+      }
+      utils::TSelector::Compound(compound) => {
+        let compound = create_compound_selector(builder, compound)?;
+        selector.push(styles::Selector::create(builder, &styles::SelectorArgs {
+          compound: Some(compound),
+          ..Default::default()
+        }));
+      }
+    }
+  }
+  let selector = builder.create_vector(&selector);
+
+  let declarations = create_declarations(
+    builder,
+    rule_item.declarations.clone(),
+    rule_item.important_declarections.clone(),
+    legacy,
+  )?;
+
+  let mut pseudo = 0;
+  let mut pseudo_key = None;
+  let mut pseudo_val = None;
+  if rule_item.selector.is_pseudo {
+    if let Some(pseudo_enum) = &rule_item.selector.pseudo_type {
+      if let Pseudo::NthChild(a, b, is_first) | Pseudo::NthOfType(a, b, is_first) = pseudo_enum {
+        pseudo_val = Some(builder.create_string(&utils::nth_child_value(*a, *b)));
+        let keys = vec![
+          create_pseudo_key(builder, Some(*a), false, legacy_v2)?,
+          create_pseudo_key(builder, Some(*b), false, legacy_v2)?,
+          create_pseudo_key(builder, None, *is_first, legacy_v2)?,
+        ];
+        pseudo_key = Some(builder.create_vector(&keys));
+      }
+      pseudo = pseudo_enum.to_f64() as u8;
+    }
+  }
+
+  // 与 JSON 对象一致，变量按名称排序，同名变量取最后一个
+  let variables = rule_item
+    .variables
+    .iter()
+    .map(|css_variable| (css_variable.id.as_str(), css_variable.value.as_str()))
+    .collect::<BTreeMap<_, _>>();
+  let variables = if variables.is_empty() {
+    None
+  } else {
+    let variables = variables
+      .into_iter()
+      .map(|(key, value)| {
+        let key = builder.create_string(key);
+        let value = builder.create_string(value);
+        styles::KeyValueString::create(builder, &styles::KeyValueStringArgs {
+          key: Some(key),
+          value: Some(value),
+        })
+      })
+      .collect::<Vec<_>>();
+    Some(builder.create_vector(&variables))
+  };
+
+  Ok(styles::Style::create(builder, &styles::StyleArgs {
+    declarations: Some(declarations),
+    media_v1,
+    pseudo,
+    pseudo_key,
+    pseudo_val,
+    selector: Some(selector),
+    variables,
+    has_env: rule_item.has_env,
+    media,
+    specificity: rule_item.specificity,
+    order: rule_item.order,
+    layer: rule_item.layer,
+    container: rule_item.container,
+    important_layer: rule_item.important_layer,
+  }))
+}
+
+fn create_string_selector<'a>(builder: &mut FlatBufferBuilder<'a>, value: &str) -> WIPOffset<styles::Selector<'a>> {
+  let string_value = builder.create_string(value);
+  styles::Selector::create(builder, &styles::SelectorArgs {
+    string_value: Some(string_value),
+    is_string: true,
+    ..Default::default()
+  })
+}
+
+fn create_compound_selector<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  compound: &utils::CompoundSelector,
+) -> Result<WIPOffset<styles::CompoundSelector<'a>>, String> {
+  let tag = compound.tag.as_ref().map(|tag| builder.create_string(tag));
+  let id = compound.id.as_ref().map(|id| builder.create_string(id));
+  let classes = if compound.classes.is_empty() {
+    None
+  } else {
+    let classes = compound
+      .classes
+      .iter()
+      .map(|class| builder.create_string(class))
+      .collect::<Vec<_>>();
+    Some(builder.create_vector(&classes))
+  };
+  let attributes = if compound.attributes.is_empty() {
+    None
+  } else {
+    let attributes = compound
+      .attributes
+      .iter()
+      .map(|attribute| {
+        let name = builder.create_string(&attribute.name);
+        let value = attribute.value.as_ref().map(|value| builder.create_string(value));
+        styles::AttributeSelector::create(builder, &styles::AttributeSelectorArgs {
+          name: Some(name),
+          operator: attribute.operator.to_f64() as u8,
+          value,
+          case_insensitive: attribute.case_insensitive,
+        })
+      })
+      .collect::<Vec<_>>();
+    Some(builder.create_vector(&attributes))
+  };
+  let negations = if compound.negations.is_empty() {
+    None
+  } else {
+    let negations = compound
+      .negations
+      .iter()
+      .map(|negation| create_compound_selector(builder, negation))
+      .collect::<Result<Vec<_>, String>>()?;
+    Some(builder.create_vector(&negations))
+  };
+  let pseudos = if compound.pseudos.is_empty() {
+    None
+  } else {
+    let pseudos = compound
+      .pseudos
+      .iter()
+      .map(|pseudo| create_pseudo_selector(builder, pseudo))
+      .collect::<Result<Vec<_>, String>>()?;
+    Some(builder.create_vector(&pseudos))
+  };
+  Ok(styles::CompoundSelector::create(builder, &styles::CompoundSelectorArgs {
+    tag,
+    id,
+    classes,
+    attributes,
+    negations,
+    pseudos,
+  }))
+}
+
+// 与 Style 上的 pseudo、pseudo_key、pseudo_val 一致，仅在版本 3 及之后写入
+fn create_pseudo_selector<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  pseudo: &Pseudo,
+) -> Result<WIPOffset<styles::PseudoSelector<'a>>, String> {
+  let (pseudo_key, pseudo_val) = match pseudo {
+    Pseudo::NthChild(a, b, is_first) | Pseudo::NthOfType(a, b, is_first) => {
+      let keys = vec![
+        create_pseudo_key(builder, Some(*a), false, false)?,
+        create_pseudo_key(builder, Some(*b), false, false)?,
+        create_pseudo_key(builder, None, *is_first, false)?,
+      ];
+      (
+        Some(builder.create_vector(&keys)),
+        Some(builder.create_string(&utils::nth_child_value(*a, *b))),
+      )
+    }
+    _ => (None, None),
+  };
+  Ok(styles::PseudoSelector::create(builder, &styles::PseudoSelectorArgs {
+    pseudo: pseudo.to_f64() as u8,
+    pseudo_key,
+    pseudo_val,
+  }))
+}
+
+fn create_pseudo_key<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  integer_value: Option<i32>,
+  bool_value: bool,
+  legacy_v2: bool,
+) -> Result<WIPOffset<styles::PseudoKey<'a>>, String> {
+  let is_int = integer_value.is_some();
+  let value = integer_value.unwrap_or(0);
+  let (integer_value_v1, integer_value) = if legacy_v2 {
+    let integer_value_v1 = i8::try_from(value).map_err(|_| {
+      format!("pseudo argument {} exceeds the version 2 binary format, use format version {}", value, FORMAT_VERSION)
+    })?;
+    (integer_value_v1, 0)
+  } else {
+    (0, value)
+  };
+  Ok(styles::PseudoKey::create(builder, &styles::PseudoKeyArgs {
+    integer_value_v1,
+    bool_value,
+    is_int,
+    integer_value,
+  }))
+}
+
+fn create_declarations<'a>(
+  builder: &mut FlatBufferBuilder<'a>,
+  value: Vec<StyleValueType>,
+  import_value: Vec<StyleValueType>,
+  legacy: bool,
+) -> Result<WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<styles::DeclarationTuple<'a>>>>, String> {
+  let mut declarations = vec![];
+  for (id, (expr, flag)) in collect_style_values(value, import_value, Platform::Harmony) {
+    // 版本 1 的 property_id_v1 为 uint8，属性 id 超过 255 时需要更高的格式版本
+    let (property_id_v1, property_id) = if legacy {
+      let property_id_v1 = u8::try_from(id)
+        .map_err(|_| format!("property id {} requires format version {}", id, FORMAT_VERSION_V2))?;
+      (property_id_v1, 0)
+    } else {
+      (0, u16::try_from(id).map_err(|_| format!("property id {} exceeds the binary format", id))?)
+    };
+    let (value_type, value) = create_value(builder, &expr)?;
+    declarations.push(styles::DeclarationTuple::create(builder, &styles::DeclarationTupleArgs {
+      property_id_v1,
+      value_type,
+      value: Some(value),
+      flag: flag.bits() as u8,
+      property_id,
+    }));
+  }
+  Ok(builder.create_vector(&declarations))
+}
+
+// 按格式版本返回 (media_v1, media)，版本 1 中 media id 超过 255 时报错，避免回绕为 0 后无条件生效
+fn media_id(media: u32, legacy: bool) -> Result<(u8, u32), String> {
+  if legacy {
+    let media_v1 = u8::try_from(media).map_err(|_| {
+      format!("media id {} exceeds the version 1 binary format, use format version {}", media, FORMAT_VERSION)
+    })?;
+    Ok((media_v1, 0))
+  } else {
+    Ok((0, media))
+  }
+}
+
+// 与 expr_to_json 的规则一致，小数部分为 0 的数字作为整数输出
+fn is_integer(value: f64) -> bool {
+  value.fract() == 0.0
+}
+
+fn array_elems(array: &ArrayLit) -> Vec<&Expr> {
+  array
+    .elems
+    .iter()
+    .filter_map(|elem| elem.as_ref().map(|elem| &*elem.expr))
+    .collect()
+}
+
+fn number_value(expr: &Expr) -> Result<f64, String> {
+  match expr {
+    Expr::Lit(Lit::Num(number)) => Ok(number.value),
+    _ => Err("expected a number in numeric array".to_string()),
+  }
+}
+
+fn create_value(builder: &mut FlatBufferBuilder, expr: &Expr) -> Result<FlatbufferValue, String> {
+  match expr {
+    Expr::Lit(Lit::Str(value)) => {
+      let value = builder.create_string(&value.value);
+      let string = styles::String::create(builder, &styles::StringArgs { value: Some(value) });
+      Ok((styles::Value::String, string.as_union_value()))
+    }
+    Expr::Lit(Lit::Num(number)) => {
+      if is_integer(number.value) {
+        let integer = styles::Integer::create(builder, &styles::IntegerArgs { value: number.value as i64 });
+        Ok((styles::Value::Integer, integer.as_union_value()))
+      } else {
+        let double = styles::Double::create(builder, &styles::DoubleArgs { value: number.value });
+        Ok((styles::Value::Double, double.as_union_value()))
+      }
+    }
+    Expr::Lit(Lit::Bool(value)) => {
+      let boolean = styles::Boolean::create(builder, &styles::BooleanArgs { value: value.value });
+      Ok((styles::Value::Boolean, boolean.as_union_value()))
+    }
+    Expr::Array(array) => create_array_value(builder, &array_elems(array)),
+    Expr::Object(object) => create_object_value(builder, object),
+    _ => Err("unsupported value expression".to_string()),
+  }
+}
+
+fn create_array_value(builder: &mut FlatBufferBuilder, elems: &[&Expr]) -> Result<FlatbufferValue, String> {
+  match elems.first() {
+    // 空数组输出为空的整数数组
+    None => {
+      let values = builder.create_vector::<i64>(&[]);
+      let array = styles::IntegerArray::create(builder, &styles::IntegerArrayArgs { values: Some(values) });
+      Ok((styles::Value::IntegerArray, array.as_union_value()))
+    }
+    Some(Expr::Lit(Lit::Str(_))) => {
+      let mut values = vec![];
+      for elem in elems.iter() {
+        match elem {
+          Expr::Lit(Lit::Str(value)) => values.push(builder.create_string(&value.value)),
+          _ => return Err("expected a string in string array".to_string()),
+        }
+      }
+      let values = builder.create_vector(&values);
+      let array = styles::StringArray::create(builder, &styles::StringArrayArgs { values: Some(values) });
+      Ok((styles::Value::StringArray, array.as_union_value()))
+    }
+    Some(Expr::Lit(Lit::Num(_))) => {
+      let values = elems.iter().map(|elem| number_value(elem)).collect::<Result<Vec<_>, _>>()?;
+      if values.iter().all(|value| is_integer(*value)) {
+        let values = values.iter().map(|value| *value as i64).collect::<Vec<_>>();
+        let values = builder.create_vector(&values);
+        let array = styles::IntegerArray::create(builder, &styles::IntegerArrayArgs { values: Some(values) });
+        Ok((styles::Value::IntegerArray, array.as_union_value()))
+      } else {
+        let values = builder.create_vector(&values);
+        let array = styles::DoubleArray::create(builder, &styles::DoubleArrayArgs { values: Some(values) });
+        Ok((styles::Value::DoubleArray, array.as_union_value()))
+      }
+    }
+    Some(Expr::Object(_)) => {
+      let mut key_values = vec![];
+      for elem in elems.iter() {
+        let (value_type, value) = create_value(builder, elem)?;
+        key_values.push(styles::KeyValue::create(builder, &styles::KeyValueArgs {
+          key: None,
+          value_type,
+          value: Some(value),
+        }));
+      }
+      let values = builder.create_vector(&key_values);
+      let array = styles::ObjectArray::create(builder, &styles::ObjectArrayArgs { values: Some(values) });
+      Ok((styles::Value::ObjectArray, array.as_union_value()))
+    }
+    // 二维数组统一输出为浮点数数组
+    Some(Expr::Array(_)) => {
+      let mut double_arrays = vec![];
+      for elem in elems.iter() {
+        let values = match elem {
+          Expr::Array(array) => array_elems(array)
+            .into_iter()
+            .map(number_value)
+            .collect::<Result<Vec<_>, _>>()?,
+          _ => return Err("expected an array in nested array".to_string()),
+        };
+        let values = builder.create_vector(&values);
+        double_arrays.push(styles::DoubleArray::create(builder, &styles::DoubleArrayArgs { values: Some(values) }));
+      }
+      let values = builder.create_vector(&double_arrays);
+      let array = styles::DoubleArrayArray::create(builder, &styles::DoubleArrayArrayArgs { values: Some(values) });
+      Ok((styles::Value::DoubleArrayArray, array.as_union_value()))
+    }
+    _ => Err("unsupported array value".to_string()),
+  }
+}
+
+fn create_object_value(builder: &mut FlatBufferBuilder, object: &ObjectLit) -> Result<FlatbufferValue, String> {
+  // 与 JSON 对象一致，字段按名称排序，同名字段取最后一个
+  let mut fields = BTreeMap::new();
+  for prop_or_spread in object.props.iter() {
+    if let PropOrSpread::Prop(prop) = prop_or_spread {
+      if let Prop::KeyValue(key_value) = &**prop {
+        let key = match &key_value.key {
+          PropName::Ident(ident) => ident.sym.to_string(),
+          PropName::Str(str) => str.value.to_string(),
+          PropName::Num(num) => num.to_string(),
+          _ => continue,
+        };
+        fields.insert(key, &*key_value.value);
+      }
+    }
+  }
+
+  let mut key_values = vec![];
+  for (key, value) in fields {
+    let key = builder.create_string(&key);
+    let (value_type, value) = create_value(builder, value)?;
+    key_values.push(styles::KeyValue::create(builder, &styles::KeyValueArgs {
+      key: Some(key),
+      value_type,
+      value: Some(value),
+    }));
+  }
+  let fields = builder.create_vector(&key_values);
+  let object = styles::Object::create(builder, &styles::ObjectArgs { fields: Some(fields) });
+  Ok((styles::Value::Object, object.as_union_value()))
+}
@@ -5,15 +5,31 @@ pub const CONVERT_STYLE_PX_FN: &'static str = "convertNumber2VP";
 pub const ENV_FUN: &'static str = "__env__";
 
 // pub const CALC_DYMAMIC_STYLE: &'static str = "calcDynamicStyle";
-pub static SUPPORT_PSEUDO_KEYS: [&'static str; 6] = [
+pub static SUPPORT_PSEUDO_KEYS: [&'static str; 15] = [
   ":before",
   ":after",
   ":first-child",
   ":last-child",
   ":nth-child",
   ":empty",
+  ":active",
+  ":focus",
+  ":disabled",
+  ":hover",
+  ":nth-of-type",
+  ":nth-last-of-type",
+  ":first-of-type",
+  ":last-of-type",
+  ":only-child",
 ];
 
+// 当前的二进制格式版本，格式说明见 flatbuffers/stylesheet.fbs
+pub const FORMAT_VERSION: u16 = 3;
+// 版本 1 的 media id、属性 id 为 uint8，写入 _v1 字段，供尚未升级的运行时使用
+pub const FORMAT_VERSION_V1: u16 = 1;
+// 版本 2 及之前的复合选择器拆分为字符串，不支持属性选择器和 :not()，PseudoKey 的整数为 int8
+pub const FORMAT_VERSION_V2: u16 = 2;
+
 pub const RN_CONVERT_STYLE_PX_FN: &'static str = "scalePx2dp";
 pub const RN_CONVERT_STYLE_VU_FN: &'static str = "scaleVu2dp";
 
@@ -27,6 +43,16 @@ pub enum Pseudo {
   LastChild,
   NthChild(i32, i32, bool),
   Empty,
+  // 交互状态，对应 ArkUI 的 stateStyles：pressed、focused、disabled、hover
+  Active,
+  Focus,
+  Disabled,
+  Hover,
+  // :nth-of-type、:nth-last-of-type，参数与 NthChild 相同
+  NthOfType(i32, i32, bool),
+  FirstOfType,
+  LastOfType,
+  OnlyChild,
 }
 
 impl Pseudo {
@@ -40,6 +66,14 @@ impl Pseudo {
       Pseudo::LastChild => 4,
       Pseudo::NthChild(_, _, _) => 5,
       Pseudo::Empty => 6,
+      Pseudo::Active => 7,
+      Pseudo::Focus => 8,
+      Pseudo::Disabled => 9,
+      Pseudo::Hover => 10,
+      Pseudo::NthOfType(_, _, _) => 11,
+      Pseudo::FirstOfType => 12,
+      Pseudo::LastOfType => 13,
+      Pseudo::OnlyChild => 14,
     }) as f64
   }
 }
@@ -51,6 +85,10 @@ pub enum SelectorType {
   Parent,
   Ancestor,
   Multiple,
+  // 相邻兄弟选择器 +
+  AdjacentSibling,
+  // 通用兄弟选择器 ~
+  GeneralSibling,
 }
 
 impl SelectorType {
@@ -60,6 +98,32 @@ impl SelectorType {
   }
 }
 
+// 属性选择器的匹配方式
+#[repr(u32)]
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum AttributeOperator {
+  // [attr]
+  Exists,
+  // [attr=value]
+  Equal,
+  // [attr~=value]
+  Includes,
+  // [attr|=value]
+  DashMatch,
+  // [attr^=value]
+  Prefix,
+  // [attr*=value]
+  Substring,
+  // [attr$=value]
+  Suffix,
+}
+
+impl AttributeOperator {
+  pub fn to_f64(self) -> f64 {
+    self as u32 as f64
+  }
+}
+
 bitflags! {
     #[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
     pub struct ValueFlag: u32 {
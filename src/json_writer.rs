@@ -3,24 +3,37 @@ use serde_json::Value;
 use swc_core::common::DUMMY_SP;
 use swc_core::ecma::ast::*;
 
-use crate::constants::{Pseudo, SUPPORT_PSEUDO_KEYS};
+use crate::constants::{Pseudo, SelectorType, FORMAT_VERSION, FORMAT_VERSION_V1, FORMAT_VERSION_V2};
 use crate::parse_style_properties::DeclsAndVars;
 use crate::style_propetries::style_value_type::StyleValueType;
 
 use crate::style_parser::{FontFaceItem, KeyFrameItem, RuleItem};
+use crate::style_propetries::style_container::StyleContainer;
 use crate::style_propetries::style_media::StyleMedia;
 use crate::style_propetries::unit::Platform;
 use crate::visitor::parse_style_values;
-use crate::{generate_expr_lit_num, generate_expr_lit_str, utils};
+use crate::{generate_expr_lit_num, generate_expr_lit_str, generate_expr_lit_str_raw, utils};
+
+// JsonWriter、BinaryWriter 的输出选项
+#[derive(Debug, Clone, Default)]
+pub struct WriterOptions {
+  pub design_width: Option<i32>,
+  pub allow_inherit: Option<bool>,
+  pub design_mode: Option<String>,
+  // 输出的格式版本，默认为当前版本
+  pub format_version: Option<u16>,
+}
 
 pub struct JsonWriter {
   styles: Vec<RuleItem>,
   keyframes: IndexMap<(u32, String), Vec<KeyFrameItem>>,
   medias: Vec<StyleMedia>,
+  containers: Vec<StyleContainer>,
   fonts: Vec<FontFaceItem>,
   design_width: Option<i32>,
   allow_inherit: Option<bool>,
   design_mode: Option<String>,
+  format_version: u16,
 }
 
 impl JsonWriter {
@@ -28,38 +41,78 @@ impl JsonWriter {
     styles: Vec<RuleItem>,
     keyframes: IndexMap<(u32, String), Vec<KeyFrameItem>>,
     medias: Vec<StyleMedia>,
+    containers: Vec<StyleContainer>,
     fonts: Vec<FontFaceItem>,
-    design_width: Option<i32>,
-    allow_inherit: Option<bool>,
-    design_mode: Option<String>,
+    options: WriterOptions,
   ) -> Self {
     Self {
       styles,
       keyframes,
       medias,
+      containers,
       fonts,
-      design_width,
-      allow_inherit,
-      design_mode,
+      design_width: options.design_width,
+      allow_inherit: options.allow_inherit,
+      design_mode: options.design_mode,
+      format_version: options.format_version.unwrap_or(FORMAT_VERSION),
     }
   }
 
-  pub fn to_json(&self) -> String {
+  pub fn to_json(&self) -> Result<String, String> {
+    if !(FORMAT_VERSION_V1..=FORMAT_VERSION).contains(&self.format_version) {
+      return Err(format!(
+        "unsupported format version {}, expected {} to {}",
+        self.format_version, FORMAT_VERSION_V1, FORMAT_VERSION
+      ));
+    }
+    let legacy_v2 = self.format_version <= FORMAT_VERSION_V2;
     let elems: Vec<Expr> = self
       .styles
       .iter()
-      .filter_map(|rule_item| {
-        Some({
-          // 识别伪类
-          let mut new_selector = rule_item.selector.selector.clone();
-          // 特殊处理:root选择器
-          if new_selector != ":root" {
-            let key_arr = new_selector.split(":").collect::<Vec<&str>>();
-            if key_arr.len() == 2 {
-              new_selector = key_arr[0].to_string();
+      .map(|rule_item| {
+        let mut selector = vec![];
+        for (index, t_selector) in rule_item.selector.nesting_selector.iter().enumerate() {
+          match t_selector {
+            // 选择器类型
+            utils::TSelector::Selector(selector_type) => selector.push(Some(ExprOrSpread {
+              spread: None,
+              expr: Box::new(Expr::Lit(Lit::Num(Number::from(selector_type.to_f64())))),
+            })),
+            utils::TSelector::String(s) => selector.push(Some(ExprOrSpread {
+              spread: None,
+              expr: Box::new(generate_expr_lit_str_raw!(s.clone())),
+            })),
+            // 版本 1、2 的复合选择器与二进制一致，拆分为字符串
+            utils::TSelector::Compound(compound) if legacy_v2 => {
+              if !compound.has_legacy_form(index == 0) {
+                return Err(format!(
+                  "attribute, :not(), non-subject or multiple pseudo selector `{}` requires format version {}",
+                  rule_item.selector.selector, FORMAT_VERSION
+                ));
+              }
+              for (index, value) in compound.to_legacy_strings().iter().enumerate() {
+                if index > 0 {
+                  selector.push(Some(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Num(Number::from(SelectorType::Multiple.to_f64())))),
+                  }));
+                }
+                selector.push(Some(ExprOrSpread {
+                  spread: None,
+                  expr: Box::new(generate_expr_lit_str_raw!(value.clone())),
+                }));
+              }
             }
+            utils::TSelector::Compound(compound) => selector.push(Some(ExprOrSpread {
+              spread: None,
+              expr: Box::new(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: compound.to_expr(),
+              })),
+            })),
           }
-          let nesting_selector = utils::split_selector(&new_selector);
+        }
+        Ok({
           let mut lit_props = vec![
             PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
               key: PropName::Ident(Ident::new("media".into(), DUMMY_SP)),
@@ -69,45 +122,7 @@ impl JsonWriter {
               key: PropName::Ident(Ident::new("selector".into(), DUMMY_SP)),
               value: Box::new(Expr::Array(ArrayLit {
                 span: DUMMY_SP,
-                elems: nesting_selector
-                  .iter()
-                  .map(|t_selector| {
-                    match t_selector {
-                      // 选择器类型
-                      utils::TSelector::Selector(selector_type) => Some(ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Lit(Lit::Num(Number::from(selector_type.to_f64())))),
-                      }),
-                      utils::TSelector::String(s) => Some(ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Lit(Lit::Str(Str {
-                          span: DUMMY_SP,
-                          value: s.clone().into(),
-                          raw: None,
-                        }))),
-                      }),
-                      utils::TSelector::Array(arr) => Some(ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Array(ArrayLit {
-                          span: DUMMY_SP,
-                          elems: arr
-                            .iter()
-                            .map(|s| {
-                              Some(ExprOrSpread {
-                                spread: None,
-                                expr: Box::new(Expr::Lit(Lit::Str(Str {
-                                  span: DUMMY_SP,
-                                  value: s.clone().into(),
-                                  raw: None,
-                                }))),
-                              })
-                            })
-                            .collect(),
-                        })),
-                      }),
-                    }
-                  })
-                  .collect(),
+                elems: selector,
               })),
             }))),
             PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
@@ -117,7 +132,31 @@ impl JsonWriter {
                 elems: parse_style_values(rule_item.declarations.clone(), rule_item.important_declarections.clone(), Platform::Harmony),
               })),
             }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+              key: PropName::Ident(Ident::new("specificity".into(), DUMMY_SP)),
+              value: Box::new(Expr::Lit(Lit::Num(Number::from(rule_item.specificity as f64)))),
+            }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+              key: PropName::Ident(Ident::new("order".into(), DUMMY_SP)),
+              value: Box::new(Expr::Lit(Lit::Num(Number::from(rule_item.order as f64)))),
+            }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+              key: PropName::Ident(Ident::new("layer".into(), DUMMY_SP)),
+              value: Box::new(Expr::Lit(Lit::Num(Number::from(rule_item.layer as f64)))),
+            }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+              key: PropName::Ident(Ident::new("important_layer".into(), DUMMY_SP)),
+              value: Box::new(Expr::Lit(Lit::Num(Number::from(rule_item.important_layer as f64)))),
+            }))),
           ];
+          if rule_item.container > 0 {
+            lit_props.push(
+              PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(Ident::new("container".into(), DUMMY_SP)),
+                value: Box::new(Expr::Lit(Lit::Num(Number::from(rule_item.container as f64)))),
+              })))
+            );
+          }
           if rule_item.has_env {
             lit_props.push(
               PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
@@ -149,20 +188,8 @@ impl JsonWriter {
 
           if rule_item.selector.is_pseudo {
             if let Some(pseudo_enum) = &rule_item.selector.pseudo_type {
-              if let Pseudo::NthChild(a, b, is_first) = &pseudo_enum {
-                let value: String = if *a == 0 && *b == 0 {
-                  "".to_string()
-                } else if *a == 0 {
-                  format!("{}", *b)
-                } else if *b == 0 {
-                  format!("{}n", *a)
-                } else {
-                  if *a == 1 {
-                    format!("n+{}", *b)
-                  } else {
-                    format!("{}n+{}", *a, *b)
-                  }
-                };
+              if let Pseudo::NthChild(a, b, is_first) | Pseudo::NthOfType(a, b, is_first) = &pseudo_enum {
+                let value: String = utils::nth_child_value(*a, *b);
                 lit_props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
                   key: PropName::Ident(Ident::new("pseudo_val".into(), DUMMY_SP)),
                   value: Box::new(Expr::Lit(Lit::Str(Str {
@@ -204,7 +231,7 @@ impl JsonWriter {
           })
         })
       })
-      .collect();
+      .collect::<Result<_, String>>()?;
 
     // keyframes
     let keyframe_elems: Vec<Expr> = self
@@ -328,6 +355,27 @@ impl JsonWriter {
         }))),
       ],
     }));
+    // 存在 @container 时才输出 containers
+    if !self.containers.is_empty() {
+      let containers = expr_to_json(&Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: self
+          .containers
+          .iter()
+          .map(|container| {
+            Some(ExprOrSpread {
+              spread: None,
+              expr: Box::new(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: container.to_expr(),
+              })),
+            })
+          })
+          .collect(),
+      }));
+      let map = json_value.as_object_mut().unwrap();
+      map.insert("containers".to_string(), containers);
+    }
     // 如果 design_width 存在，则添加 design_width 到 json_value
     if let Some(design_width) = self.design_width {
       let map = json_value.as_object_mut().unwrap();
@@ -347,7 +395,7 @@ impl JsonWriter {
     // let json_string = serde_json::to_string_pretty(&json_value).unwrap();
     let json_string = serde_json::to_string(&json_value).unwrap();
 
-    json_string
+    Ok(json_string)
   }
 }
 
@@ -1,19 +1,29 @@
 #![deny(clippy::all)]
 
+use std::rc::Rc;
+
 use serde::Deserialize;
 use napi::bindgen_prelude::Buffer;
+use napi::{Env, JsFunction, JsObject, ValueType};
 
-use json_writer::JsonWriter;
+use binary_reader::decode_stylesheet;
+use binary_writer::BinaryWriter;
+use json_writer::{JsonWriter, WriterOptions};
+use source_map::SourceMapWriter;
 use style_parser::StyleParser;
 use style_propetries::unit::Platform;
+use utils::root_dir_import_resolver;
 
 #[macro_use]
 extern crate napi_derive;
 
 mod stylesheet_generated;
+mod binary_reader;
+mod binary_writer;
 mod constants;
 mod json_writer;
 mod parse_style_properties;
+mod source_map;
 mod style_parser;
 mod style_propetries;
 mod utils;
@@ -39,76 +49,1620 @@ pub struct ParseOptions {
   pub output: Option<OutputOptions>,
   pub allow_inherit: Option<bool>,
   pub design_mode: Option<String>,
+  // 严格模式，存在不支持的属性、选择器或无法解析的值时不输出结果，所有问题都记录在 errors 中
+  pub strict: Option<bool>,
+  // 是否输出 source map，记录每条样式、声明、keyframes、media 对应的原始样式位置
+  pub source_map: Option<bool>,
+  // 二进制格式版本，默认为当前版本，迁移期间可指定为 1 以兼容旧版运行时
+  // 同时决定 JSON 中选择器的结构：版本 3 的复合选择器为 { tag, id, classes, attributes, negations, pseudos } 对象，
+  // 版本 1、2 与旧版一致，拆分为字符串，如 view#header.item => ["item", 3, "#header", 3, "view"]，无法表示时报错且不输出结果
+  pub format_version: Option<u32>,
+  // @import 的根目录，相对地址基于所在文件的目录，以 / 开头的地址基于根目录，传入 resolve 时不生效
+  pub import_root: Option<String>,
+}
+
+#[napi(object)]
+pub struct DecodeOptions {
+  // 是否将属性 id 转换为属性名
+  pub property_names: Option<bool>,
+}
+
+#[napi(object)]
+pub struct StyleInput {
+  pub filename: String,
+  pub code: String,
+}
+
+#[napi(object)]
+pub struct ParseError {
+  pub message: String,
+  // 出错的文件名，parseFiles 或 @import 引入的文件会返回
+  pub filename: Option<String>,
+  // 行号，相对于所在的样式字符串，从 1 开始
+  pub line: u32,
+  pub column: u32,
+  // 出错的样式字符串在 styles 或 inputs 中的下标
+  pub index: u32,
+}
+
+#[napi(object)]
+pub struct ParseWarning {
+  pub message: String,
+  // 声明所在的选择器，keyframes 中的声明为 @keyframes name
+  pub selector: String,
+  pub property: String,
+  pub value: String,
+  // unknown-property | unsupported-value | unsupported-selector | unsupported-query
+  pub reason: String,
+  pub filename: Option<String>,
+  // 声明所在规则的位置，相对于所在的样式字符串，从 1 开始
+  pub line: u32,
+  pub column: u32,
+  pub index: u32,
 }
 
 #[napi(object)]
 pub struct ParseResult {
   pub code: Option<String>,
   pub buffer: Option<Buffer>,
+  pub errors: Vec<ParseError>,
+  pub warnings: Vec<ParseWarning>,
+  pub source_map: Option<String>,
+}
+
+// 将拼接后样式中的行号映射回原始样式字符串的下标和行号
+fn locate_style_line(styles: &[String], line: u32) -> (u32, u32) {
+  let mut start_line = 1;
+  for (index, style) in styles.iter().enumerate() {
+    let line_count = style.matches('\n').count() as u32 + 1;
+    if line < start_line + line_count || index == styles.len() - 1 {
+      return (index as u32, line - start_line + 1);
+    }
+    start_line += line_count;
+  }
+  (0, line)
 }
 
+// resolve(url, importer) 用于读取 @import 的文件，返回 { filename, code }，文件不存在时返回 null
 #[napi]
-pub fn parse(styles: Vec<String>, options: ParseOptions) -> ParseResult {
-  let platform = match options.platform_string.as_str() {
+pub fn parse(env: Env, styles: Vec<String>, options: ParseOptions, resolve: Option<JsFunction>) -> ParseResult {
+  // 解析样式文件
+  let css = styles.join("\n");
+  let mut style_parser = StyleParser::new(get_platform(&options));
+  set_import_resolver(&mut style_parser, &env, &options, resolve.as_ref());
+  style_parser.parse(&css);
+  // 拼接后的样式只有一个 source_index，需要根据行号找回原始样式字符串
+  let sources = vec![String::new(); styles.len()];
+  generate_result(style_parser, options, sources, |_, line| {
+    let (index, line) = locate_style_line(&styles, line);
+    (index, line, None)
+  })
+}
+
+#[napi]
+pub fn parse_files(env: Env, inputs: Vec<StyleInput>, options: ParseOptions, resolve: Option<JsFunction>) -> ParseResult {
+  // 每个文件单独解析，保留各自的文件名和行号
+  let mut style_parser = StyleParser::new(get_platform(&options));
+  set_import_resolver(&mut style_parser, &env, &options, resolve.as_ref());
+  for input in inputs.iter() {
+    style_parser.parse_file(&input.filename, &input.code);
+  }
+  let sources = inputs.iter().map(|input| input.filename.clone()).collect();
+  generate_result(style_parser, options, sources, |index, line| {
+    let filename = inputs
+      .get(index as usize)
+      .map(|input| input.filename.clone());
+    (index, line, filename)
+  })
+}
+
+// 将二进制样式还原为 JSON，便于调试，没有文件标识的旧二进制按版本 1 读取
+#[napi]
+pub fn decode(buffer: Buffer, options: Option<DecodeOptions>) -> napi::Result<String> {
+  let property_names = options
+    .and_then(|options| options.property_names)
+    .unwrap_or(false);
+  decode_stylesheet(&buffer, property_names).map_err(napi::Error::from_reason)
+}
+
+fn set_import_resolver<'a>(
+  style_parser: &mut StyleParser<'a>,
+  env: &'a Env,
+  options: &ParseOptions,
+  resolve: Option<&'a JsFunction>,
+) {
+  if let Some(resolve) = resolve {
+    style_parser.set_import_resolver(Rc::new(move |url: &str, importer: &str| {
+      call_import_resolver(env, resolve, url, importer)
+    }));
+  } else if let Some(import_root) = &options.import_root {
+    style_parser.set_import_resolver(Rc::new(root_dir_import_resolver(import_root)));
+  }
+}
+
+fn call_import_resolver(env: &Env, resolve: &JsFunction, url: &str, importer: &str) -> Result<(String, String), String> {
+  let args = [
+    env.create_string(url).map_err(|error| error.reason)?,
+    env.create_string(importer).map_err(|error| error.reason)?,
+  ];
+  let result = resolve.call(None, &args).map_err(|error| error.reason)?;
+  if result.get_type().map_err(|error| error.reason)? != ValueType::Object {
+    return Err(format!("cannot find imported file `{}`", url));
+  }
+  let result: JsObject = unsafe { result.cast() };
+  let filename = result.get_named_property::<String>("filename").map_err(|error| error.reason)?;
+  let code = result.get_named_property::<String>("code").map_err(|error| error.reason)?;
+  Ok((filename, code))
+}
+
+fn get_platform(options: &ParseOptions) -> Platform {
+  match options.platform_string.as_str() {
     "ReactNative" => Platform::ReactNative,
     "Harmony" => Platform::Harmony,
     _ => Platform::Harmony,
+  }
+}
+
+// locate 根据输入的下标和行号返回输入的下标、输入内的行号以及文件名
+fn generate_result<F>(style_parser: StyleParser, options: ParseOptions, mut sources: Vec<String>, locate: F) -> ParseResult
+where
+  F: Fn(u32, u32) -> (u32, u32, Option<String>),
+{
+  // 直接传入的样式文件按顺序对应输入的下标，@import 引入的文件追加到 source map 的 sources 中
+  let style_sources = style_parser.sources.borrow().clone();
+  let mut input_count = 0;
+  let source_indexes = style_sources
+    .iter()
+    .map(|source| match source.importer {
+      Some(_) => {
+        sources.push(source.filename.clone());
+        sources.len() as u32 - 1
+      }
+      None => {
+        input_count += 1;
+        input_count - 1
+      }
+    })
+    .collect::<Vec<u32>>();
+  // @import 引入的文件，下标为最外层 @import 所在的输入
+  let locate = |source_index: u32, line: u32| {
+    let source = &style_sources[source_index as usize];
+    match source.importer {
+      Some(mut loc) => {
+        while let Some(importer) = style_sources[loc.source_index as usize].importer {
+          loc = importer;
+        }
+        let (index, _, _) = locate(source_indexes[loc.source_index as usize], loc.line + 1);
+        (index, line, Some(source.filename.clone()))
+      }
+      None => locate(source_indexes[source_index as usize], line),
+    }
+  };
+
+  let writer_options = WriterOptions {
+    design_width: options.design_width,
+    allow_inherit: options.allow_inherit,
+    design_mode: options.design_mode,
+    format_version: options
+      .format_version
+      .map(|version| u16::try_from(version).unwrap_or(u16::MAX)),
   };
-  let design_width = options.design_width;
-  let allow_inherit = options.allow_inherit;
-  let design_mode = options.design_mode;
+  let strict = options.strict.unwrap_or(false);
+  let with_source_map = options.source_map.unwrap_or(false);
   let output = options.output.unwrap_or(OutputOptions {
     is_bin: Some(false)
   });
   let is_bin = output.is_bin.unwrap_or(false);
 
-  // 解析样式文件
-  let css = styles.join("\n");
-  let mut style_parser = StyleParser::new(platform.clone());
-  style_parser.parse(&css);
   let style_data = style_parser.calc();
+  let mut errors = style_parser
+    .errors
+    .iter()
+    .map(|error| {
+      let (index, line, filename) = if error.line > 0 {
+        locate(error.source_index, error.line)
+      } else {
+        (error.source_index, 0, None)
+      };
+      ParseError {
+        message: error.message.clone(),
+        filename,
+        line,
+        column: error.column,
+        index,
+      }
+    })
+    .collect::<Vec<ParseError>>();
+  let mut warnings = style_data
+    .warnings
+    .iter()
+    .map(|warning| {
+      let (index, line, filename) = locate(warning.source_index, warning.line);
+      ParseWarning {
+        message: warning.message(),
+        selector: warning.selector.clone(),
+        property: warning.property.clone(),
+        value: warning.value.clone(),
+        reason: warning.reason.as_str().to_string(),
+        filename,
+        line,
+        column: warning.column,
+        index,
+      }
+    })
+    .collect::<Vec<ParseWarning>>();
+
+  // 严格模式下，将所有警告提升为错误，并且不再输出结果
+  if strict && (!errors.is_empty() || !warnings.is_empty()) {
+    errors.extend(warnings.drain(..).map(|warning| ParseError {
+      message: warning.message,
+      filename: warning.filename,
+      line: warning.line,
+      column: warning.column,
+      index: warning.index,
+    }));
+    errors.sort_by_key(|error| (error.index, error.line, error.column));
+    return ParseResult {
+      code: None,
+      buffer: None,
+      errors,
+      warnings,
+      source_map: None,
+    };
+  }
+
+  let source_map = if with_source_map {
+    let source_map_writer = SourceMapWriter::new(
+      style_data.all_style.borrow().clone(),
+      style_data.all_keyframes.borrow().clone(),
+      style_data.all_medias.borrow().clone(),
+      style_data.all_containers.borrow().clone(),
+      style_data.all_fonts.borrow().clone(),
+      sources,
+    );
+    Some(source_map_writer.to_json(|loc| {
+      if style_sources[loc.source_index as usize].importer.is_some() {
+        return (source_indexes[loc.source_index as usize], loc.line + 1, loc.column);
+      }
+      let (index, line, _) = locate(loc.source_index, loc.line + 1);
+      (index, line, loc.column)
+    }))
+  } else {
+    None
+  };
 
   // 解析过滤器
 
+  if is_bin {
+    // 直接从解析结果输出二进制，不再经过 JSON 中转
+    let binary_writer = BinaryWriter::new(
+      style_data.all_style.borrow().clone(),
+      style_data.all_keyframes.borrow().clone(),
+      style_data.all_medias.borrow().clone(),
+      style_data.all_containers.borrow().clone(),
+      style_data.all_fonts.borrow().clone(),
+      writer_options.clone(),
+    );
+    match binary_writer.to_binary() {
+      Ok(buffer) => {
+        return ParseResult {
+          code: None,
+          buffer: Some(Buffer::from(buffer)),
+          errors,
+          warnings,
+          source_map,
+        };
+      }
+      // 写入失败时记录错误，并回退到 JSON 输出
+      Err(message) => errors.push(ParseError {
+        message,
+        filename: None,
+        line: 0,
+        column: 0,
+        index: 0,
+      }),
+    }
+  }
+
   // 输出成JSON格式
   let style_map = JsonWriter::new(
     style_data.all_style.borrow().clone(),
     style_data.all_keyframes.borrow().clone(),
     style_data.all_medias.borrow().clone(),
+    style_data.all_containers.borrow().clone(),
     style_data.all_fonts.borrow().clone(),
-    design_width,
-    allow_inherit,
-    design_mode
+    writer_options,
   );
 
-  let style_json = style_map.to_json();
-  if is_bin {
-    // 输出到文件
-    let convert_result = utils::convert_json_to_flatbuffer(&style_json);
-    if let Ok(buffer) = convert_result {
-      return ParseResult {
-        code: None,
-        buffer: Some(Buffer::from(buffer)),
-      };
+  let code = match style_map.to_json() {
+    Ok(code) => Some(code),
+    Err(message) => {
+      // 二进制回退到 JSON 时可能已记录相同的错误
+      if !errors.iter().any(|error| error.message == message) {
+        errors.push(ParseError {
+          message,
+          filename: None,
+          line: 0,
+          column: 0,
+          index: 0,
+        });
+      }
+      None
     }
-    return ParseResult {
-      code: Some(style_json),
-      buffer: None
-    };
-  }
+  };
 
   ParseResult {
-    code: Some(style_json),
-    buffer: None
+    code,
+    buffer: None,
+    errors,
+    warnings,
+    source_map,
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::utils::convert_json_to_flatbuffer;
+  use std::rc::Rc;
+  use crate::utils::{convert_json_to_flatbuffer, root_dir_import_resolver};
+  use crate::binary_reader::decode_stylesheet;
+  use crate::binary_writer::BinaryWriter;
+  use crate::constants::{FORMAT_VERSION, FORMAT_VERSION_V1, FORMAT_VERSION_V2};
+  use crate::json_writer::{JsonWriter, WriterOptions};
+  use crate::locate_style_line;
+  use crate::source_map::SourceMapWriter;
+  use crate::parse_style_properties::DropReason;
+  use crate::style_parser::{FontSource, StyleData, StyleParser};
+  use crate::style_propetries::style_property_type::{
+    css_property_type_to_string, string_to_css_property_type, CSSPropertyType, CSS_PROPERTY_NAMES,
+  };
+  use indexmap::IndexMap;
+  use crate::style_propetries::unit::Platform;
   use serde_json::json;
   use crate::stylesheet_generated::styles;
 
+  fn json_writer(
+    style_data: &StyleData,
+    design_width: Option<i32>,
+    allow_inherit: Option<bool>,
+    design_mode: Option<&str>,
+    format_version: Option<u16>,
+  ) -> JsonWriter {
+    JsonWriter::new(
+      style_data.all_style.borrow().clone(),
+      style_data.all_keyframes.borrow().clone(),
+      style_data.all_medias.borrow().clone(),
+      style_data.all_containers.borrow().clone(),
+      style_data.all_fonts.borrow().clone(),
+      WriterOptions {
+        design_width,
+        allow_inherit,
+        design_mode: design_mode.map(|design_mode| design_mode.to_string()),
+        format_version,
+      },
+    )
+  }
+
+  fn binary_writer(
+    style_data: &StyleData,
+    design_width: Option<i32>,
+    allow_inherit: Option<bool>,
+    design_mode: Option<&str>,
+    format_version: Option<u16>,
+  ) -> BinaryWriter {
+    BinaryWriter::new(
+      style_data.all_style.borrow().clone(),
+      style_data.all_keyframes.borrow().clone(),
+      style_data.all_medias.borrow().clone(),
+      style_data.all_containers.borrow().clone(),
+      style_data.all_fonts.borrow().clone(),
+      WriterOptions {
+        design_width,
+        allow_inherit,
+        design_mode: design_mode.map(|design_mode| design_mode.to_string()),
+        format_version,
+      },
+    )
+  }
+
+  fn to_json(style_data: &StyleData) -> String {
+    json_writer(style_data, None, None, None, None).to_json().unwrap()
+  }
+
+  fn to_binary(style_data: &StyleData, format_version: Option<u16>) -> Result<Vec<u8>, String> {
+    binary_writer(style_data, None, None, None, format_version).to_binary()
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    let styles = vec![
+      ".a { color: red; }".to_string(),
+      ".b { color: blue; }\n.c..x { color: red; }\n.d { width: 10px; }".to_string(),
+    ];
+    let css = styles.join("\n");
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(&css);
+    let style_data = style_parser.calc();
+    let selectors = style_data
+      .all_style
+      .borrow()
+      .iter()
+      .map(|rule_item| rule_item.selector.selector.clone())
+      .collect::<Vec<_>>();
+    assert!(selectors.contains(&".a".to_string()));
+    assert!(selectors.contains(&".d".to_string()));
+
+    assert!(!style_parser.errors.is_empty());
+    let error = &style_parser.errors[0];
+    assert_eq!(locate_style_line(&styles, error.line), (1, 2));
+  }
+
+  #[test]
+  fn test_parse_warnings() {
+    let styles = vec![
+      ".a {\n  color: red;\n  foo-bar: 1;\n}".to_string(),
+      ".b { width: 10px; display: inherit; }\n@keyframes fade { from, to { foo-bar: 0; } }".to_string(),
+    ];
+    let css = styles.join("\n");
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(&css);
+    let style_data = style_parser.calc();
+
+    let warnings = &style_data.warnings;
+    assert_eq!(warnings.len(), 3);
+    assert_eq!(warnings[0].selector, ".a");
+    assert_eq!(warnings[0].property, "foo-bar");
+    assert_eq!(warnings[0].reason, DropReason::UnknownProperty);
+    assert_eq!(locate_style_line(&styles, warnings[0].line), (0, 1));
+    assert_eq!(warnings[1].selector, ".b");
+    assert_eq!(warnings[1].property, "display");
+    assert_eq!(warnings[1].reason, DropReason::UnsupportedValue);
+    assert_eq!(warnings[2].selector, "@keyframes fade");
+    assert_eq!(locate_style_line(&styles, warnings[2].line), (1, 2));
+  }
+
+  #[test]
+  fn test_unsupported_selectors() {
+    let css = ".a:checked { color: red; }\n.a + .b { color: red; }\n.a::placeholder { color: red; }\n.c::before, .c > .d:nth-child(2n) { color: red; }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let warnings = style_data
+      .warnings
+      .iter()
+      .map(|warning| (warning.selector.as_str(), warning.reason, warning.line))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      warnings,
+      vec![
+        (".a:checked", DropReason::UnsupportedSelector, 1),
+        (".a::placeholder", DropReason::UnsupportedSelector, 3),
+      ]
+    );
+
+    // 无法转换的选择器对应的规则不输出，选择器列表中的其他选择器不受影响
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let selectors = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| style["selector"].clone())
+      .collect::<Vec<_>>();
+    assert_eq!(selectors.len(), 3);
+    assert!(!selectors.contains(&json!(["a"])));
+  }
+
+  #[test]
+  fn test_state_pseudo_classes() {
+    let css = ".btn { opacity: 1; }\n.btn:active { opacity: 0.6; }\n.input:focus { width: 10px; }\n.btn:disabled { opacity: 0.3; }\n.link:hover { height: 10px; }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+    assert!(style_data.warnings.is_empty());
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let pseudos = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["selector"].clone(), style["pseudo"].clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      pseudos,
+      vec![
+        (json!([{"classes": ["btn"]}]), serde_json::Value::Null),
+        (json!([{"classes": ["btn"], "pseudos": [{"pseudo": 7}]}]), json!(7)),
+        (json!([{"classes": ["input"], "pseudos": [{"pseudo": 8}]}]), json!(8)),
+        (json!([{"classes": ["btn"], "pseudos": [{"pseudo": 9}]}]), json!(9)),
+        (json!([{"classes": ["link"], "pseudos": [{"pseudo": 10}]}]), json!(10)),
+      ]
+    );
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let style_sheet = styles::root_as_style_sheet(&buffer).unwrap();
+    let pseudos = style_sheet.styles().unwrap().iter().map(|style| style.pseudo()).collect::<Vec<_>>();
+    assert_eq!(pseudos, vec![0, 7, 8, 9, 10]);
+  }
+
+
+  #[test]
+  fn test_sibling_selectors() {
+    let css = ".a + .b { color: red; }\n.x .a ~ .b.c > .d { color: red; }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+    assert!(style_data.warnings.is_empty());
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    assert_eq!(json_value["styles"][0]["selector"], json!([{"classes": ["b"]}, 4, {"classes": ["a"]}]));
+    assert_eq!(
+      json_value["styles"][1]["selector"],
+      json!([{"classes": ["d"]}, 1, {"classes": ["b", "c"]}, 5, {"classes": ["a"]}, 2, {"classes": ["x"]}])
+    );
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+  }
+
+  #[test]
+  fn test_typed_selectors() {
+    let css = "#header { width: 10px; }\nview.item.active > * { width: 10px; }\n[data-active] { width: 10px; }\ninput[type=\"text\" i] { width: 10px; }\n.a[lang|=en] { width: 10px; }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+    assert!(style_data.warnings.is_empty());
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let selectors = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| style["selector"].clone())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      selectors,
+      vec![
+        json!([{"id": "header"}]),
+        json!([{"tag": "*"}, 1, {"tag": "view", "classes": ["item", "active"]}]),
+        json!([{"attributes": [{"name": "data-active", "operator": 0}]}]),
+        json!([{"tag": "input", "attributes": [{"name": "type", "operator": 1, "value": "text", "case_insensitive": true}]}]),
+        json!([{"classes": ["a"], "attributes": [{"name": "lang", "operator": 3, "value": "en"}]}]),
+      ]
+    );
+
+    let writer = |format_version: Option<u16>| to_binary(&style_data, format_version);
+    let buffer = writer(None).unwrap();
+    let style_sheet = styles::root_as_style_sheet(&buffer).unwrap();
+    let compound = style_sheet.styles().unwrap().get(1).selector().unwrap().get(2).compound().unwrap();
+    assert_eq!(compound.tag(), Some("view"));
+    assert_eq!(compound.classes().unwrap().iter().collect::<Vec<_>>(), vec!["item", "active"]);
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    assert_eq!(format!("{:?}", styles::root_as_style_sheet(&json_buffer).unwrap()), format!("{:?}", style_sheet));
+
+    // 旧版本格式无法表示属性选择器
+    assert!(writer(Some(FORMAT_VERSION_V2)).unwrap_err().contains("[data-active]"));
+  }
+
+  #[test]
+  fn test_is_where_not_selectors() {
+    let css = ":is(.a, view.b) .c { width: 10px; }\n.d:where(.e, #f)::before { content: 'x'; }\n.item:not(.disabled, [hidden]) { width: 10px; }\n.g > .h:is(.i .j) { width: 10px; }\n.k:not(.l .m) { width: 10px; }\n.n { height: 1px; }\n:where(.n) { height: 2px; }\n.x:is(.y, #z) { height: 3px; }\n.x.y { height: 4px; }\n.p:has(.q) { width: 1px; }\n.r { & > .u:is(.s .t) { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let selectors = style_data
+      .all_style
+      .borrow()
+      .iter()
+      .map(|rule_item| rule_item.selector.selector.clone())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      selectors,
+      vec![".a .c", "view.b .c", ".d.e:before", ".d#f:before", ".item:not(.disabled, [hidden])", ".n", ".n", ".x.y", ".x#z", ".x.y"]
+    );
+    // 无法展开或表示的选择器，对应的规则不输出
+    let warnings = style_data.warnings.iter().map(|warning| warning.selector.as_str()).collect::<Vec<_>>();
+    assert_eq!(warnings, vec![".g > .h:is(.i .j)", ".k:not(.l .m)", ".p:has(.q)", ".r > .u:is(.s .t)"]);
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    assert_eq!(json_value["styles"][1]["selector"], json!([{"classes": ["c"]}, 2, {"tag": "view", "classes": ["b"]}]));
+    assert_eq!(json_value["styles"][3]["selector"], json!([{"id": "f", "classes": ["d"], "pseudos": [{"pseudo": 1}]}]));
+    assert_eq!(json_value["styles"][3]["pseudo"], json!(1));
+    assert_eq!(
+      json_value["styles"][4]["selector"],
+      json!([{"classes": ["item"], "negations": [{"classes": ["disabled"]}, {"attributes": [{"name": "hidden", "operator": 0}]}]}])
+    );
+    // :where() 不增加优先级，:is() 取参数中最高的优先级
+    let cascade = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .skip(5)
+      .map(|style| (style["specificity"].clone(), style["declarations"].clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      cascade,
+      vec![
+        (json!(1 << 10), json!([[25, 1]])),
+        (json!(0), json!([[25, 2]])),
+        (json!((1 << 20) + (1 << 10)), json!([[25, 3]])),
+        (json!((1 << 20) + (1 << 10)), json!([[25, 3]])),
+        (json!(2 << 10), json!([[25, 4]])),
+      ]
+    );
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    assert_eq!(
+      format!("{:?}", styles::root_as_style_sheet(&json_buffer).unwrap()),
+      format!("{:?}", styles::root_as_style_sheet(&buffer).unwrap())
+    );
+  }
+
+  #[test]
+  fn test_structural_pseudo_classes() {
+    let css = ".a:nth-of-type(2n+1) { width: 1px; }\n.b:nth-last-of-type(200) { width: 1px; }\n.c:first-of-type { width: 1px; }\n.d:last-of-type { width: 1px; }\n.e:only-child { width: 1px; }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+    assert!(style_data.warnings.is_empty());
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let pseudos = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["pseudo"].clone(), style["pseudo_key"].clone(), style["pseudo_val"].clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      pseudos,
+      vec![
+        (json!(11), json!([2, 1, true]), json!("2n+1")),
+        (json!(11), json!([0, 200, false]), json!("200")),
+        (json!(12), serde_json::Value::Null, serde_json::Value::Null),
+        (json!(13), serde_json::Value::Null, serde_json::Value::Null),
+        (json!(14), serde_json::Value::Null, serde_json::Value::Null),
+      ]
+    );
+
+    let writer = |format_version: Option<u16>| to_binary(&style_data, format_version);
+    let buffer = writer(None).unwrap();
+    let style_sheet = styles::root_as_style_sheet(&buffer).unwrap();
+    assert_eq!(style_sheet.styles().unwrap().get(1).pseudo_key().unwrap().get(1).integer_value(), 200);
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    assert_eq!(format!("{:?}", styles::root_as_style_sheet(&json_buffer).unwrap()), format!("{:?}", style_sheet));
+
+    // 旧版本格式中 PseudoKey 的整数为 int8，超出时报错而不是回绕
+    assert!(writer(Some(FORMAT_VERSION_V2)).unwrap_err().contains("pseudo argument 200"));
+  }
+
+  #[test]
+  fn test_specificity_and_order() {
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse_file("a.css", ".b { color: red; }\n.c { color: blue; }\n.a .b { height: 1px; }\n#x { width: 1px; }");
+    style_parser.parse_file("b.css", ".b { width: 1px; }\n@media (min-width: 100px) { view.c { width: 1px; } }");
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let cascade = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["specificity"].clone(), style["order"].clone()))
+      .collect::<Vec<_>>();
+    // 不同规则中的相同选择器不合并，各自保留所在规则的顺序
+    assert_eq!(
+      cascade,
+      vec![
+        (json!(1 << 10), json!(0)),
+        (json!(1 << 10), json!(1)),
+        (json!(2 << 10), json!(2)),
+        (json!(1 << 20), json!(3)),
+        (json!(1 << 10), json!(4)),
+        (json!((1 << 10) + 1), json!(5)),
+      ]
+    );
+
+    // 同时匹配 .b、.c 的元素按 (specificity, order) 依次应用声明，.c 的 color 在第一个 .b 之后，最终为 blue
+    let mut matched = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .filter(|style| style["media"] == json!(0) && [json!([{"classes": ["b"]}]), json!([{"classes": ["c"]}])].contains(&style["selector"]))
+      .collect::<Vec<_>>();
+    matched.sort_by_key(|style| (style["specificity"].as_u64(), style["order"].as_u64()));
+    let mut computed = IndexMap::new();
+    for style in matched {
+      for declaration in style["declarations"].as_array().unwrap() {
+        computed.insert(declaration[0].clone(), declaration[1].clone());
+      }
+    }
+    let blue = json_value["styles"][1]["declarations"][0].clone();
+    assert_eq!(computed[&blue[0]], blue[1]);
+    assert_eq!(computed.len(), 2);
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let style_sheet = styles::root_as_style_sheet(&buffer).unwrap();
+    assert_eq!(style_sheet.styles().unwrap().get(3).specificity(), 1 << 20);
+    assert_eq!(style_sheet.styles().unwrap().get(4).order(), 4);
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    assert_eq!(format!("{:?}", styles::root_as_style_sheet(&json_buffer).unwrap()), format!("{:?}", style_sheet));
+  }
+
+  #[test]
+  fn test_nesting() {
+    let css = ".card {\n  width: 1px;\n  &:first-child { height: 1px; }\n  .title { color: red; }\n  > view.icon { width: 2px; }\n  @media (min-width: 100px) { width: 3px; }\n}\n.a, #b { & .c { color: red; } }\n.list { .item { &.active { color: red; } } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    assert!(style_parser.errors.is_empty());
+    let style_data = style_parser.calc();
+    assert!(style_data.warnings.is_empty());
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let styles = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["selector"].clone(), style["media"].clone(), style["specificity"].clone()))
+      .collect::<Vec<_>>();
+    // 只包含嵌套规则的 .a, #b、.list、.list .item 不输出，& 的优先级取父选择器中最高的
+    assert_eq!(
+      styles,
+      vec![
+        (json!([{"classes": ["card"]}]), json!(0), json!(1 << 10)),
+        (json!([{"classes": ["card"], "pseudos": [{"pseudo": 3}]}]), json!(0), json!(2 << 10)),
+        (json!([{"classes": ["title"]}, 2, {"classes": ["card"]}]), json!(0), json!(2 << 10)),
+        (json!([{"tag": "view", "classes": ["icon"]}, 1, {"classes": ["card"]}]), json!(0), json!((2 << 10) + 1)),
+        (json!([{"classes": ["card"]}]), json!(1), json!(1 << 10)),
+        (json!([{"classes": ["c"]}, 2, {"classes": ["a"]}]), json!(0), json!((1 << 20) + (1 << 10))),
+        (json!([{"classes": ["c"]}, 2, {"id": "b"}]), json!(0), json!((1 << 20) + (1 << 10))),
+        (json!([{"classes": ["item", "active"]}, 2, {"classes": ["list"]}]), json!(0), json!(3 << 10)),
+      ]
+    );
+    let lines = style_data
+      .all_style
+      .borrow()
+      .iter()
+      .map(|rule_item| (rule_item.selector.selector.clone(), rule_item.loc.line + 1))
+      .collect::<Vec<_>>();
+    assert_eq!(lines[1], (".card:first-child".to_string(), 3));
+    assert_eq!(lines[4], (".card".to_string(), 6));
+  }
+
+  #[test]
+  fn test_compound_pseudos() {
+    let css = ".row:first-child::before { width: 1px; }\n.list:nth-child(2n) .item { width: 1px; }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+    assert!(style_data.warnings.is_empty());
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    assert_eq!(
+      json_value["styles"][0]["selector"],
+      json!([{"classes": ["row"], "pseudos": [{"pseudo": 3}, {"pseudo": 1}]}])
+    );
+    assert_eq!(json_value["styles"][0]["pseudo"], json!(1));
+    assert_eq!(
+      json_value["styles"][1]["selector"],
+      json!([
+        {"classes": ["item"]},
+        2,
+        {"classes": ["list"], "pseudos": [{"pseudo": 5, "pseudo_key": [2, 0, true], "pseudo_val": "2n"}]}
+      ])
+    );
+
+    let writer = |format_version: Option<u16>| to_binary(&style_data, format_version);
+    let buffer = writer(None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    assert_eq!(
+      format!("{:?}", styles::root_as_style_sheet(&json_buffer).unwrap()),
+      format!("{:?}", styles::root_as_style_sheet(&buffer).unwrap())
+    );
+
+    // 旧版本格式只能在 Style 上记录最后一个复合选择器的一个伪类或伪元素
+    assert!(writer(Some(FORMAT_VERSION_V2)).unwrap_err().contains(".row:first-child:before"));
+    for (css, legacy) in [(".list:nth-child(2n) .item { width: 1px; }", false), (".row::before { width: 1px; }", true)] {
+      let mut style_parser = StyleParser::new(Platform::Harmony);
+      style_parser.parse(css);
+      let result = to_binary(&style_parser.calc(), Some(FORMAT_VERSION_V1));
+      assert_eq!(result.is_ok(), legacy, "{}", css);
+    }
+  }
+
+  #[test]
+  fn test_imports() {
+    let files = [
+      ("theme.css", ".t { width: 2px; }"),
+      ("loop.css", "@import \"loop2.css\";\n.l { width: 3px; }"),
+      ("loop2.css", "@import \"loop.css\";"),
+    ];
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.set_import_resolver(Rc::new(move |url: &str, _: &str| {
+      files
+        .iter()
+        .find(|(filename, _)| *filename == url)
+        .map(|(filename, code)| (filename.to_string(), code.to_string()))
+        .ok_or(format!("cannot find imported file `{}`", url))
+    }));
+    style_parser.parse_file(
+      "main.css",
+      "@import \"theme.css\" (min-width: 100px);\n@import \"missing.css\";\n@import \"loop.css\";\n.a { width: 1px; }",
+    );
+    let style_data = style_parser.calc();
+
+    let styles = style_data
+      .all_style
+      .borrow()
+      .iter()
+      .map(|rule_item| (rule_item.selector.selector.clone(), rule_item.media, rule_item.loc.source_index))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      styles,
+      vec![(".t".to_string(), 1, 1), (".l".to_string(), 0, 2), (".a".to_string(), 0, 0)]
+    );
+    assert_eq!(style_data.all_medias.borrow().len(), 1);
+    let sources = style_parser
+      .sources
+      .borrow()
+      .iter()
+      .map(|source| (source.filename.clone(), source.importer.map(|loc| (loc.source_index, loc.line + 1))))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      sources,
+      vec![
+        ("main.css".to_string(), None),
+        ("theme.css".to_string(), Some((0, 1))),
+        ("loop.css".to_string(), Some((0, 3))),
+        ("loop2.css".to_string(), Some((2, 1))),
+      ]
+    );
+    let errors = style_parser
+      .errors
+      .iter()
+      .map(|error| (error.message.as_str(), error.source_index, error.line))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      errors,
+      vec![
+        ("cannot find imported file `missing.css`", 0, 2),
+        ("circular @import of `loop.css`", 3, 1),
+      ]
+    );
+
+    // 以根目录读取文件，相对地址基于所在文件的目录
+    let root = std::env::temp_dir().join(format!("parse-css-imports-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("common")).unwrap();
+    std::fs::write(root.join("common/base.css"), "@import \"../theme.css\";\n.b { width: 1px; }").unwrap();
+    std::fs::write(root.join("theme.css"), ".t { width: 2px; }").unwrap();
+    let resolver = root_dir_import_resolver(root.to_str().unwrap());
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.set_import_resolver(Rc::new(resolver));
+    style_parser.parse_file("pages/index.css", "@import \"/common/base.css\";\n@import \"./missing.css\";");
+    let style_data = style_parser.calc();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    let selectors = style_data
+      .all_style
+      .borrow()
+      .iter()
+      .map(|rule_item| rule_item.selector.selector.clone())
+      .collect::<Vec<_>>();
+    assert_eq!(selectors, vec![".t", ".b"]);
+    assert!(style_parser.sources.borrow()[2].filename.ends_with("theme.css"));
+    assert_eq!(style_parser.errors.len(), 1);
+    assert_eq!(style_parser.errors[0].message, "cannot find imported file `./missing.css`");
+  }
+
+  #[test]
+  fn test_layers() {
+    let css = "@layer reset, base;\n.a { color: red; }\n@layer base { .a { color: blue; width: 2px !important; } @layer inner { .b { width: 1px; } } }\n@layer reset { .a { width: 1px !important; } }\n@layer { .c { width: 1px; } }\n@layer base.inner { .d { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let layers = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["selector"][0]["classes"][0].clone(), style["layer"].clone(), style["important_layer"].clone()))
+      .collect::<Vec<_>>();
+    // reset < base.inner < base < 匿名层 < 不在层中的样式，important 声明的顺序相反，不同层中的 .a 不合并
+    assert_eq!(
+      layers,
+      vec![
+        (json!("a"), json!(4), json!(0)),
+        (json!("a"), json!(2), json!(2)),
+        (json!("b"), json!(1), json!(3)),
+        (json!("a"), json!(0), json!(4)),
+        (json!("c"), json!(3), json!(1)),
+        (json!("d"), json!(1), json!(3)),
+      ]
+    );
+    // base、reset 中都有 !important 的 width，先声明的 reset 层生效
+    let important_widths = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .filter(|style| style["selector"] == json!([{"classes": ["a"]}]))
+      .flat_map(|style| {
+        style["declarations"]
+          .as_array()
+          .unwrap()
+          .iter()
+          .filter(|declaration| declaration.get(2).is_some())
+          .map(|declaration| (style["important_layer"].as_u64(), declaration[1].clone()))
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(important_widths.len(), 2);
+    let important_width = important_widths
+      .into_iter()
+      .max_by_key(|(important_layer, _)| *important_layer)
+      .map(|(_, value)| value);
+    assert_eq!(important_width, Some(json!(1)));
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    assert_eq!(styles::root_as_style_sheet(&buffer).unwrap().styles().unwrap().get(1).layer(), 2);
+    assert_eq!(styles::root_as_style_sheet(&buffer).unwrap().styles().unwrap().get(3).important_layer(), 4);
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+
+    // 旧版本格式无法表示层的优先级
+    assert!(to_binary(&style_data, Some(FORMAT_VERSION_V2)).unwrap_err().contains("@layer style `.a`"));
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(".a { color: red; }");
+    assert!(to_binary(&style_parser.calc(), Some(FORMAT_VERSION_V1)).is_ok());
+  }
+
+  #[test]
+  fn test_container_properties() {
+    let css = ".card { container: card / inline-size; }\n.b { container-name: none; }\n.c { container-type: size; container-name: x y; }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+    assert!(style_data.warnings.is_empty());
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let declarations = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| style["declarations"].clone())
+      .collect::<Vec<_>>();
+    // container 复合属性拆分为 container-type、container-name，none 为空数组
+    assert_eq!(
+      declarations,
+      vec![
+        json!([[118, 2], [119, ["card"]]]),
+        json!([[119, []]]),
+        json!([[118, 1], [119, ["x", "y"]]]),
+      ]
+    );
+    assert_eq!(json_value["containers"], json!(null));
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+  }
+
+  #[test]
+  fn test_container_nesting() {
+    let css = ".a { width: 1px; }\n@container card (min-width: 400px) { .a { width: 2px; } @container (orientation: landscape) { .a { width: 3px; } } .b { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let styles = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["selector"][0]["classes"][0].clone(), style["container"].clone()))
+      .collect::<Vec<_>>();
+    // 不同 @container 中的 .a 不合并，嵌套结束后恢复外层的 container
+    assert_eq!(
+      styles,
+      vec![
+        (json!("a"), json!(null)),
+        (json!("a"), json!(1)),
+        (json!("a"), json!(2)),
+        (json!("b"), json!(1)),
+      ]
+    );
+    assert_eq!(
+      json_value["containers"],
+      json!([
+        { "id": 1, "name": "card", "conditions": [[0, [1, 2, "400vp"]]] },
+        { "id": 2, "parent": 1, "conditions": [[0, [5, 0, "landscape"]]] },
+      ])
+    );
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&json_buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+
+    // 旧版本格式无法表示 @container 中的样式
+    assert!(to_binary(&style_data, Some(FORMAT_VERSION_V1)).unwrap_err().contains("@container style `.a`"));
+  }
+
+  #[test]
+  fn test_container_ranges() {
+    let css = "@container (100px < inline-size < 200px) { .a { width: 1px; } }\n@container (200px > block-size >= 100px) { .b { width: 1px; } }\n@container (width > 100px) { .c { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    // 区间拆分为两个比较，起始值在特性名左侧，比较方向相反，如 100px < inline-size 即 inline-size > 100px
+    assert_eq!(
+      json_value["containers"],
+      json!([
+        { "id": 1, "conditions": [[2, [[0, [11, 1, "100vp"]], [0, [11, 3, "200vp"]]]]] },
+        { "id": 2, "conditions": [[2, [[0, [12, 3, "200vp"]], [0, [12, 2, "100vp"]]]]] },
+        { "id": 3, "conditions": [[0, [1, 1, "100vp"]]] },
+      ])
+    );
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+  }
+
+  #[test]
+  fn test_container_unsupported_queries() {
+    let css = "@container (min-width: 1px) and style(--x: 1) { .a { width: 1px; } }\n@container not style(--x: 1) { .b { width: 1px; } }\n@container (orientation: landscape) or (min-width: 1px) { .c { width: 1px; } }\n@container (foo: 1) { .d { width: 1px; } }\n@container (min-width: calc(1em + 10px)) { .e { width: 1px; } }\n@container (min-width: env(x)) { .f { width: 1px; } }\n@container (100px < width < calc(1em + 10px)) { .g { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    // 样式查询、无法识别的特性、calc()、env() 无法求值，区间的任意一端无法求值时整个区间无法求值
+    // 包含它们的 and、not 不做化简，整条规则不输出
+    let warnings = style_data
+      .warnings
+      .iter()
+      .map(|warning| (warning.selector.as_str(), warning.reason))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      warnings,
+      vec![
+        ("@container (width >= 1px) and style(--x: 1)", DropReason::UnsupportedQuery),
+        ("@container not style(--x: 1)", DropReason::UnsupportedQuery),
+        ("@container (foo: 1)", DropReason::UnsupportedQuery),
+        ("@container (width >= calc(1em + 10px))", DropReason::UnsupportedQuery),
+        ("@container (width >= env(x))", DropReason::UnsupportedQuery),
+        ("@container (100px < width < calc(1em + 10px))", DropReason::UnsupportedQuery),
+      ]
+    );
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    assert_eq!(json_value["styles"].as_array().unwrap().len(), 1);
+    assert_eq!(json_value["styles"][0]["container"], json!(1));
+    assert_eq!(
+      json_value["containers"],
+      json!([{ "id": 1, "conditions": [[3, [[0, [5, 0, "landscape"]], [0, [1, 2, "1vp"]]]]] }])
+    );
+  }
+
+  #[test]
+  fn test_font_faces() {
+    let regular = r#"@font-face { src: local("F Regular"), url(f.woff2) format("woff2"), url(f.ttf); font-weight: 100 400; font-family: f; font-display: swap; }"#;
+    let css = r#"
+      @font-face { src: local("F Regular"), url(f.woff2) format("woff2"), url(f.ttf); font-weight: 100 400; font-family: f; font-display: swap; }
+      @font-face { font-family: f; src: url(f-bold.ttf); font-weight: bold; font-style: italic; unicode-range: U+0000-00FF, U+4E00-9FFF; }
+      @font-face { font-family: f; src: url(f-light.ttf); font-weight: 100 400; }
+      @font-face { font-family: g; src: local(G); }
+    "#;
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    // 同一字体族的不同字重分别输出，字重相同时后出现的覆盖先出现的
+    assert_eq!(
+      json_value["fonts"],
+      json!([
+        {
+          "fontFamily": "f",
+          "src": "f-light.ttf",
+          "sources": [{ "url": "f-light.ttf" }],
+          "fontWeight": 0,
+          "fontWeightRange": [100, 400],
+        },
+        {
+          "fontFamily": "f",
+          "src": "f-bold.ttf",
+          "sources": [{ "url": "f-bold.ttf" }],
+          "fontStyle": 1,
+          "fontWeight": 9,
+          "fontWeightRange": [700, 700],
+          "unicodeRange": [[0, 255], [19968, 40959]],
+        },
+        { "fontFamily": "g", "src": "", "sources": [{ "local": "G" }] },
+      ])
+    );
+
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(regular);
+    let style_data = style_parser.calc();
+    let fonts = style_data.all_fonts.borrow();
+    assert_eq!(fonts[0].src, "f.woff2");
+    assert_eq!(
+      fonts[0].sources,
+      vec![
+        FontSource::Local("F Regular".to_string()),
+        FontSource::Url { url: "f.woff2".to_string(), format: Some("woff2".to_string()) },
+        FontSource::Url { url: "f.ttf".to_string(), format: None },
+      ]
+    );
+    assert_eq!(fonts[0].font_display.as_deref(), Some("swap"));
+
+    drop(fonts);
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let buffer = to_binary(&style_data, None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&json_buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+  }
+
+  #[test]
+  fn test_media_dedup_and_nesting() {
+    let css = "@media (min-width: 600px) { .a { width: 1px; } }\n@media (width >= 600px) { .a { height: 1px; } @media (orientation: landscape) { .b { width: 1px; } } .c { width: 1px; } }\n@media (orientation: landscape) { .d { width: 1px; } }\n@media (min-width: 600px) { @media (orientation: landscape) { .e { width: 1px; } } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let medias = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["selector"][0]["classes"][0].clone(), style["media"].clone()))
+      .collect::<Vec<_>>();
+    // 相同条件的 @media 合并为同一个 id，嵌套结束后恢复外层的 id
+    assert_eq!(
+      medias,
+      vec![
+        (json!("a"), json!(1)),
+        (json!("a"), json!(1)),
+        (json!("b"), json!(2)),
+        (json!("c"), json!(1)),
+        (json!("d"), json!(3)),
+        (json!("e"), json!(2)),
+      ]
+    );
+    assert_eq!(
+      json_value["medias"],
+      json!([
+        { "id": 1, "conditions": [[0, [1, 2, "600vp"]]] },
+        { "id": 2, "conditions": [[2, [[0, [1, 2, "600vp"]], [0, [5, 0, "landscape"]]]]] },
+        { "id": 3, "conditions": [[0, [5, 0, "landscape"]]] },
+      ])
+    );
+  }
+
+  #[test]
+  fn test_media_types_and_query_lists() {
+    let css = "@media print { .a { width: 1px; } }\n@media only screen and (min-width: 600px) { .b { width: 1px; } }\n@media not print, (orientation: landscape) { .c { width: 1px; } }\n@media all { .d { width: 1px; } }\n@media not all { .e { width: 1px; } }\n@media screen, all { .f { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    // 媒体类型为 feature 13，逗号分隔的查询以 or 条件表示，all 恒成立不输出条件
+    assert_eq!(
+      json_value["medias"],
+      json!([
+        { "id": 1, "conditions": [[0, [13, 0, "print"]]] },
+        { "id": 2, "conditions": [[2, [[0, [13, 0, "screen"]], [0, [1, 2, "600vp"]]]]] },
+        { "id": 3, "conditions": [[3, [[1, [[0, [13, 0, "print"]]]], [0, [5, 0, "landscape"]]]]] },
+        { "id": 4, "conditions": [] },
+        { "id": 5, "conditions": [[1, [[2, []]]]] },
+      ])
+    );
+    assert_eq!(json_value["styles"][5]["media"], json!(4));
+
+    let buffer = to_binary(&style_data, None).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&json_buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, json_value);
+
+    // 旧版本格式无法表示媒体类型和 or 条件
+    assert!(to_binary(&style_data, Some(FORMAT_VERSION_V2)).unwrap_err().contains("media 1 with a media type"));
+    for css in ["@media (min-width: 1px), (orientation: landscape) { .a { width: 1px; } }", "@media (min-width: 1px) or (orientation: landscape) { .a { width: 1px; } }"] {
+      let mut style_parser = StyleParser::new(Platform::Harmony);
+      style_parser.parse(css);
+      assert!(to_binary(&style_parser.calc(), Some(FORMAT_VERSION_V1)).unwrap_err().contains("`or` condition"));
+    }
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse("@media (min-width: 1px) and (orientation: landscape) { .a { width: 1px; } }");
+    assert!(to_binary(&style_parser.calc(), Some(FORMAT_VERSION_V1)).is_ok());
+  }
+
+  #[test]
+  fn test_media_ranges() {
+    let css = "@media (400px < width < 800px) { .a { width: 1px; } }\n@media (800px >= height > 400px) { .b { width: 1px; } }\n@media (width <= 600px) { .c { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    // 区间拆分为两个比较，起始值在特性名左侧，比较方向相反
+    assert_eq!(
+      json_value["medias"],
+      json!([
+        { "id": 1, "conditions": [[2, [[0, [1, 1, "400vp"]], [0, [1, 3, "800vp"]]]]] },
+        { "id": 2, "conditions": [[2, [[0, [2, 4, "800vp"]], [0, [2, 1, "400vp"]]]]] },
+        { "id": 3, "conditions": [[0, [1, 4, "600vp"]]] },
+      ])
+    );
+  }
+
+  #[test]
+  fn test_unsupported_media_queries() {
+    let css = "@media (hover: hover), (min-width: 600px) { .a { width: 1px; } }\n@media (hover: hover) { .b { width: 1px; } }\n@media not (hover: hover) { .c { width: 1px; } }\n@media (min-width: 600px) and (hover: hover), all { .d { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    // 无法求值的查询视为不成立，从列表中移除，全部无法求值时其中的规则不输出
+    let warnings = style_data
+      .warnings
+      .iter()
+      .map(|warning| (warning.selector.as_str(), warning.reason, warning.line))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      warnings,
+      vec![
+        ("@media (hover: hover)", DropReason::UnsupportedQuery, 1),
+        ("@media (hover: hover)", DropReason::UnsupportedQuery, 2),
+        ("@media not (hover: hover)", DropReason::UnsupportedQuery, 3),
+        ("@media (width >= 600px) and (hover: hover)", DropReason::UnsupportedQuery, 4),
+      ]
+    );
+
+    let style_json = to_json(&style_data);
+    let json_value: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    let styles = json_value["styles"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|style| (style["selector"][0]["classes"][0].clone(), style["media"].clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(styles, vec![(json!("a"), json!(1)), (json!("d"), json!(2))]);
+    assert_eq!(
+      json_value["medias"],
+      json!([
+        { "id": 1, "conditions": [[0, [1, 2, "600vp"]]] },
+        { "id": 2, "conditions": [] },
+      ])
+    );
+  }
+
+  #[test]
+  fn test_supports() {
+    let css = "@supports (display: flex) and (width: calc(100% - 10px)) { .a { width: 1px; } }\n@supports (display: inherit) { .b { width: 1px; } }\n@supports not (foo: bar) { .c { width: 1px; } }\n@supports selector(.x > .y) or (foo: bar) { .d { width: 1px; } }\n@supports selector(.x:checked) { .e { width: 1px; } }\n.f { @supports (--x: 1) { width: 1px; } }\n@supports font-tech(color-COLRv1) { .g { width: 1px; } }";
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let selectors = style_data
+      .all_style
+      .borrow()
+      .iter()
+      .map(|rule_item| rule_item.selector.selector.clone())
+      .collect::<Vec<_>>();
+    assert_eq!(selectors, vec![".a", ".c", ".d", ".f"]);
+  }
+
+  #[test]
+  fn test_parse_files() {
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse_file("a.css", ".a { color: red; }\n.b { color: red; }");
+    style_parser.parse_file("b.css", "\n.b { width: 10px; }\n.c..x { color: red; }\n@font-face { font-family: f; src: url(f.ttf); }");
+    let style_data = style_parser.calc();
+
+    let sources = style_data
+      .all_style
+      .borrow()
+      .iter()
+      .map(|rule_item| (rule_item.selector.selector.clone(), rule_item.loc.source_index))
+      .collect::<Vec<_>>();
+    assert_eq!(sources, vec![(".a".to_string(), 0), (".b".to_string(), 0), (".b".to_string(), 1)]);
+    assert_eq!(style_data.all_fonts.borrow()[0].loc.source_index, 1);
+
+    let error = &style_parser.errors[0];
+    assert_eq!((error.source_index, error.line), (1, 3));
+  }
+
+  #[test]
+  fn test_source_map() {
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse_file("a.css", ".a { color: red; }");
+    style_parser.parse_file("b.css", "\n.a { width: 10px; }\n@media (min-width: 100px) {\n  .b { height: 10px; }\n}\n@keyframes fade { from { opacity: 0; } }");
+    let style_data = style_parser.calc();
+
+    let source_map_writer = SourceMapWriter::new(
+      style_data.all_style.borrow().clone(),
+      style_data.all_keyframes.borrow().clone(),
+      style_data.all_medias.borrow().clone(),
+      style_data.all_containers.borrow().clone(),
+      style_data.all_fonts.borrow().clone(),
+      vec!["a.css".to_string(), "b.css".to_string()],
+    );
+    let source_map: serde_json::Value = serde_json::from_str(&source_map_writer.to_json(|loc| {
+      (loc.source_index, loc.line + 1, loc.column)
+    })).unwrap();
+
+    assert_eq!(source_map["sources"], json!(["a.css", "b.css"]));
+    assert_eq!(source_map["styles"][0]["loc"], json!([0, 1, 1]));
+    assert_eq!(source_map["styles"][0]["declarations"], json!([[0, 1, 1]]));
+    assert_eq!(source_map["styles"][1]["loc"], json!([1, 2, 1]));
+    assert_eq!(source_map["styles"][2]["loc"], json!([1, 4, 3]));
+    assert_eq!(source_map["medias"], json!([[1, 3, 1]]));
+    assert_eq!(source_map["keyframes"], json!([[1, 6, 1]]));
+  }
+
+  #[test]
+  fn test_binary_writer() {
+    let css = r#"
+      .app { --color: red; width: 100px; height: var(--h); color: #ff0000 !important; }
+      .app > .tit .img { transform: translate(10px, 20px) rotate(30deg); box-shadow: 1px 2px 3px #000; }
+      .hello::before { content: "hello"; }
+      .item:nth-child(2n+1) { margin: 10px 5px; flex: 1; }
+      @media (min-width: 100px) and (max-width: 200px) { .m { opacity: 0.5; } }
+      @keyframes fade { from { opacity: 0; } 50% { opacity: 0.5; } to { opacity: 1; } }
+      @font-face { font-family: f; src: url(f.ttf); }
+      @font-face { font-family: g; src: url(g.ttf); font-weight: bold; }
+      .safe { padding-top: env(safe-area-inset-top); }
+    "#;
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = json_writer(&style_data, Some(750), Some(true), Some("vw"), None).to_json().unwrap();
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    let binary_buffer = binary_writer(&style_data, Some(750), Some(true), Some("vw"), None).to_binary().unwrap();
+
+    let json_style_sheet = styles::root_as_style_sheet(&json_buffer).unwrap();
+    let binary_style_sheet = styles::root_as_style_sheet(&binary_buffer).unwrap();
+    assert_eq!(binary_style_sheet.styles().unwrap().len(), 6);
+    assert_eq!(binary_style_sheet.design_mode(), Some("vw"));
+    assert!(binary_style_sheet.styles().unwrap().get(5).has_env());
+    assert_eq!(binary_style_sheet.fonts().unwrap().get(0).font_weight(), None);
+    assert!(binary_style_sheet.fonts().unwrap().get(1).font_weight().is_some());
+    assert_eq!(format!("{:?}", binary_style_sheet), format!("{:?}", json_style_sheet));
+  }
+
+  #[test]
+  fn test_binary_media_ids_over_255() {
+    let css = (0..300)
+      .map(|index| format!("@media (min-width: {}px) {{ .m{} {{ width: {}px; }} }}", index, index, index))
+      .collect::<Vec<String>>()
+      .join("\n");
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(&css);
+    let style_data = style_parser.calc();
+
+    let style_json = to_json(&style_data);
+    let json_buffer = convert_json_to_flatbuffer(&style_json).unwrap();
+    let binary_buffer = to_binary(&style_data, None).unwrap();
+
+    for buffer in [&json_buffer, &binary_buffer] {
+      let style_sheet = styles::root_as_style_sheet(buffer).unwrap();
+      let medias = style_sheet.medias().unwrap();
+      let styles = style_sheet.styles().unwrap();
+      assert_eq!(medias.len(), 300);
+      assert_eq!(styles.len(), 300);
+      // 第 256 个及之后的 @media 不再回绕为 0
+      assert_eq!(medias.get(299).id(), 300);
+      let last_style = styles.iter().find(|style| style.selector().unwrap().get(0).compound().unwrap().classes().unwrap().get(0) == "m299").unwrap();
+      assert_eq!(last_style.media(), 300);
+      assert_eq!(last_style.declarations().unwrap().get(0).property_id(), 22);
+    }
+
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&binary_buffer, false).unwrap()).unwrap();
+    let expected: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    assert_eq!(decoded, expected);
+  }
+
+  #[test]
+  fn test_binary_format_version() {
+    let css = r#"
+      .app { width: 100px; color: red !important; }
+      @media (min-width: 100px) { .m { opacity: 0.5; } }
+      @keyframes fade { from { opacity: 0; } to { opacity: 1; } }
+      .app > view#header.item { height: 10px; }
+      .f:nth-child(3) { height: 10px; }
+    "#;
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+    let writer = |format_version: Option<u16>| to_binary(&style_data, format_version);
+    let style_json = to_json(&style_data);
+    let expected: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+
+    // 默认输出当前版本，并带有文件标识
+    let buffer = writer(None).unwrap();
+    assert!(styles::style_sheet_buffer_has_identifier(&buffer));
+    assert_eq!(styles::root_as_style_sheet(&buffer).unwrap().format_version(), FORMAT_VERSION);
+
+    // 版本 1 只写入 _v1 字段，复合选择器拆分为字符串
+    let buffer = writer(Some(FORMAT_VERSION_V1)).unwrap();
+    let style_sheet = styles::root_as_style_sheet(&buffer).unwrap();
+    assert_eq!(style_sheet.format_version(), FORMAT_VERSION_V1);
+    let media_style = style_sheet.styles().unwrap().iter().find(|style| style.selector().unwrap().get(0).string_value() == Some("m")).unwrap();
+    assert_eq!(media_style.media_v1(), 1);
+    assert_eq!(media_style.media(), 0);
+    assert_eq!(media_style.declarations().unwrap().get(0).property_id(), 0);
+    assert_eq!(style_sheet.medias().unwrap().get(0).id_v1(), 1);
+    let mut expected_legacy = expected.clone();
+    expected_legacy["styles"][0]["selector"] = json!(["app"]);
+    expected_legacy["styles"][1]["selector"] = json!(["m"]);
+    expected_legacy["styles"][2]["selector"] = json!(["item", 3, "#header", 3, "view", 1, "app"]);
+    expected_legacy["styles"][3]["selector"] = json!(["f"]);
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    assert_eq!(decoded, expected_legacy);
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&writer(Some(FORMAT_VERSION_V2)).unwrap(), false).unwrap()).unwrap();
+    assert_eq!(decoded, expected_legacy);
+
+    // JSON 输出同样按版本拆分复合选择器，与二进制解码结果一致，无法用旧格式表示时报错
+    let legacy_json = |style_data: &StyleData, format_version: u16| {
+      json_writer(style_data, None, None, None, Some(format_version)).to_json()
+    };
+    for format_version in [FORMAT_VERSION_V1, FORMAT_VERSION_V2] {
+      let style_json: serde_json::Value = serde_json::from_str(&legacy_json(&style_data, format_version).unwrap()).unwrap();
+      assert_eq!(style_json, expected_legacy);
+    }
+    let mut attribute_parser = StyleParser::new(Platform::Harmony);
+    attribute_parser.parse(".a[data-x] { height: 10px; }");
+    let attribute_data = attribute_parser.calc();
+    assert!(legacy_json(&attribute_data, FORMAT_VERSION_V2).unwrap_err().contains("requires format version"));
+    assert!(json_writer(&attribute_data, None, None, None, None).to_json().is_ok());
+
+    // 不支持的版本
+    assert!(writer(Some(0)).is_err());
+    assert!(writer(Some(FORMAT_VERSION + 1)).is_err());
+    assert!(legacy_json(&style_data, 0).is_err());
+    assert!(legacy_json(&style_data, FORMAT_VERSION + 1).is_err());
+
+    // 没有文件标识时按版本 1 读取，无法读取时报错，拒绝版本过新的二进制
+    let mut unknown = buffer.clone();
+    unknown[4..8].copy_from_slice(b"XXXX");
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&unknown, false).unwrap()).unwrap();
+    assert_eq!(decoded, expected_legacy);
+    assert!(decode_stylesheet(&[], false).unwrap_err().contains("file identifier"));
+    assert!(decode_stylesheet(&[1, 2, 3, 4, 5, 6, 7, 8], false).unwrap_err().contains("file identifier"));
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let style_sheet = styles::StyleSheet::create(&mut builder, &styles::StyleSheetArgs {
+      format_version: FORMAT_VERSION + 1,
+      ..Default::default()
+    });
+    styles::finish_style_sheet_buffer(&mut builder, style_sheet);
+    assert!(decode_stylesheet(builder.finished_data(), false).unwrap_err().contains("unsupported format version"));
+  }
+
+  #[test]
+  fn test_decode_unversioned_buffer() {
+    // 按加入文件标识和版本信息之前的布局生成，只包含 _v1 字段
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let value = styles::Integer::create(&mut builder, &styles::IntegerArgs { value: 100 });
+    let declaration = styles::DeclarationTuple::create(&mut builder, &styles::DeclarationTupleArgs {
+      property_id_v1: 22,
+      value_type: styles::Value::Integer,
+      value: Some(value.as_union_value()),
+      ..Default::default()
+    });
+    let declarations = builder.create_vector(&[declaration]);
+    let app = builder.create_string("app");
+    let selector = styles::Selector::create(&mut builder, &styles::SelectorArgs {
+      string_value: Some(app),
+      is_string: true,
+      ..Default::default()
+    });
+    let selector = builder.create_vector(&[selector]);
+    let style = styles::Style::create(&mut builder, &styles::StyleArgs {
+      declarations: Some(declarations),
+      media_v1: 1,
+      selector: Some(selector),
+      ..Default::default()
+    });
+    let styles = builder.create_vector(&[style]);
+    let style_sheet = styles::StyleSheet::create(&mut builder, &styles::StyleSheetArgs {
+      styles: Some(styles),
+      design_width: 750,
+      ..Default::default()
+    });
+    builder.finish(style_sheet, None);
+
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(builder.finished_data(), false).unwrap()).unwrap();
+    assert_eq!(decoded["styles"][0]["selector"], json!(["app"]));
+    assert_eq!(decoded["styles"][0]["media"], json!(1));
+    assert_eq!(decoded["styles"][0]["declarations"], json!([[22, 100]]));
+    assert_eq!(decoded["design_width"], json!(750));
+  }
+
+  #[test]
+  fn test_binary_v1_media_ids_over_255() {
+    let css = (0..300)
+      .map(|index| format!("@media (min-width: {}px) {{ .m{} {{ width: {}px; }} }}", index, index, index))
+      .collect::<Vec<String>>()
+      .join("\n");
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(&css);
+    let style_data = style_parser.calc();
+    let result = to_binary(&style_data, Some(FORMAT_VERSION_V1));
+    // 版本 1 无法表示超过 255 的 media id，报错而不是回绕
+    assert!(result.unwrap_err().contains("media id 256"));
+  }
+
+  #[test]
+  fn test_decode() {
+    let css = r#"
+      .app { --color: red; width: 100px; color: #ff0000 !important; }
+      .app > .tit { transform: translate(10px, 20px) rotate(30deg); box-shadow: 1px 2px 3px #000; }
+      .item:nth-child(2n+1) { margin: 10px 5px; }
+      @media (min-width: 100px) { .m { opacity: 0.5; } }
+      @keyframes fade { from { opacity: 0; } 50% { opacity: 0.5; } }
+      @font-face { font-family: f; src: url(f.ttf); font-weight: 500; }
+      .safe { padding-bottom: env(safe-area-inset-bottom); }
+    "#;
+    let mut style_parser = StyleParser::new(Platform::Harmony);
+    style_parser.parse(css);
+    let style_data = style_parser.calc();
+
+    let style_json = json_writer(&style_data, Some(750), None, Some("vp"), None).to_json().unwrap();
+    let buffer = binary_writer(&style_data, Some(750), None, Some("vp"), None).to_binary().unwrap();
+
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    let expected: serde_json::Value = serde_json::from_str(&style_json).unwrap();
+    assert_eq!(decoded, expected);
+
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, true).unwrap()).unwrap();
+    assert_eq!(decoded["styles"][0]["declarations"][0][0], json!("width"));
+    assert_eq!(decoded["styles"][0]["declarations"][1], json!(["color", 4294901760u32, 2]));
+
+    assert!(decode_stylesheet(&[0, 1, 2], false).is_err());
+  }
+
   #[test]
   fn test_valid_input() {
     let json_input = json!({"fonts":[],"keyframes":[],"medias":[],"styles":[{"declarations":[[22,293],[42,4278753764u32],[25, "var(--h)", 1]],"media":0,"selector":["app"],"variables":{"--color":"red"}},{"declarations":[[41,4294901760u32]],"media":0,"selector":["tit"]},{"declarations":[[29,24],[41,4291979550u32]],"media":0,"selector":["tit",2,"app"]},{"declarations":[[22,100],[25,100]],"media":0,"selector":["img"]}, {"declarations":[[79,"hello"]],"media":0,"pseudo":1,"selector":["hello"]},{"declarations":[[42,4294967264u32]],"media":0,"pseudo":5,"pseudo_key":[2,0,true],"pseudo_val":"2n","selector":["bbb"]}]}).to_string();
@@ -206,6 +1760,39 @@ mod tests {
     assert_eq!(sixth_style.pseudo_val().unwrap(), "2n");
   }
 
+  #[test]
+  fn test_valid_input_with_fonts_and_env() {
+    let json_input = json!({"fonts":[{"fontFamily":"f","src":"url(f.ttf)"},{"fontFamily":"g","src":"url(g.ttf)","fontWeight":0}],"keyframes":[],"medias":[],"styles":[{"declarations":[[22,293]],"media":0,"selector":["app"],"specificity":1024,"order":0,"layer":0,"important_layer":0},{"declarations":[[25,"env(safe-area-inset-top)"]],"has_env":true,"media":0,"selector":["safe"],"specificity":1024,"order":1,"layer":0,"important_layer":0}],"design_width":750,"design_mode":"vw"}).to_string();
+
+    let result = convert_json_to_flatbuffer(&json_input);
+    assert!(result.is_ok());
+
+    let buffer = result.unwrap();
+    let style_sheet = styles::root_as_style_sheet(&buffer).unwrap();
+
+    // 验证 fonts，fontWeight 为 0 时与未设置区分
+    let fonts = style_sheet.fonts().unwrap();
+    assert_eq!(fonts.len(), 2);
+    assert_eq!(fonts.get(0).font_family().unwrap(), "f");
+    assert_eq!(fonts.get(0).font_weight(), None);
+    assert_eq!(fonts.get(1).src().unwrap(), "url(g.ttf)");
+    assert_eq!(fonts.get(1).font_weight(), Some(0));
+
+    // 验证 has_env
+    let styles = style_sheet.styles().unwrap();
+    assert!(!styles.get(0).has_env());
+    assert!(styles.get(1).has_env());
+
+    // 验证 design_width、design_mode
+    assert_eq!(style_sheet.design_width(), 750);
+    assert_eq!(style_sheet.design_mode().unwrap(), "vw");
+
+    // 解码后与输入的 JSON 一致
+    let decoded: serde_json::Value = serde_json::from_str(&decode_stylesheet(&buffer, false).unwrap()).unwrap();
+    let expected: serde_json::Value = serde_json::from_str(&json_input).unwrap();
+    assert_eq!(decoded, expected);
+  }
+
   #[test]
   fn test_keyframes() {
     // 创建一个包含keyframes的JSON测试用例
@@ -293,4 +1880,20 @@ mod tests {
     assert_eq!(declarations100.get(0).property_id(), 22);
     assert_eq!(declarations100.get(0).value_as_integer().unwrap().value(), 360);
   }
+
+  #[test]
+  fn test_css_property_names() {
+    // 属性名与属性 id 可以互相转换，不存在重复的属性名或 id
+    for (index, (property_type, name)) in CSS_PROPERTY_NAMES.iter().enumerate() {
+      assert_eq!(string_to_css_property_type(name), *property_type);
+      assert_eq!(css_property_type_to_string(*property_type as u32), Some(*name));
+      assert!(CSS_PROPERTY_NAMES[index + 1..]
+        .iter()
+        .all(|(other_type, other_name)| other_type != property_type && other_name != name));
+    }
+    // 不在列表中的属性
+    assert_eq!(string_to_css_property_type("unknownProperty"), CSSPropertyType::Invalid);
+    assert_eq!(css_property_type_to_string(CSSPropertyType::Invalid as u32), None);
+    assert_eq!(css_property_type_to_string(CSSPropertyType::All as u32), None);
+  }
 }
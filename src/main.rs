@@ -1,4 +1,4 @@
-use json_writer::JsonWriter;
+use json_writer::{JsonWriter, WriterOptions};
 use style_parser::StyleParser;
 use style_propetries::unit::Platform;
 
@@ -30,13 +30,12 @@ pub fn main() {
     style_data.all_style.borrow().clone(),
     style_data.all_keyframes.borrow().clone(),
     style_data.all_medias.borrow().clone(),
+    style_data.all_containers.borrow().clone(),
     style_data.all_fonts.borrow().clone(),
-    None,
-    None,
-    None,
+    WriterOptions::default(),
   );
 
-  let style_json = style_map.to_json();
+  let style_json = style_map.to_json().unwrap();
   print!("{}", style_json);
   let convert_result = utils::convert_json_to_flatbuffer(&style_json);
   if let Ok(buffer) = convert_result {
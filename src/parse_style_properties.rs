@@ -1,6 +1,6 @@
 use lightningcss::{
-  properties::{custom::TokenOrValue, Property},
-  stylesheet::PrinterOptions, traits::ToCss,
+  properties::{custom::TokenOrValue, Property, PropertyId},
+  stylesheet::{ParserOptions, PrinterOptions}, traits::ToCss,
 };
 use swc_core::ecma::ast::*;
 use swc_core::{
@@ -15,26 +15,106 @@ use crate::{
   generate_expr_lit_str,
   style_parser::KeyFrameItem,
   style_propetries::{
-    animation::Animation, animation_multi::AnimationMulti, aspect_ratio::AspectRatio, background::Background, background_image::BackgroundImage, background_position::BackgroundPosition, background_repeat::BackgroundRepeat, background_size::BackgroundSize, border::Border, border_color::BorderColor, border_radius::BorderRadius, border_style::BorderStyle, border_width::BorderWidth, box_orient::BoxOrient, box_shadow::BoxShadow, color::ColorProperty, display::Display, expr::Expr, flex::Flex, flex_align::FlexAlign, flex_basis::FlexBasis, flex_direction::FlexDirection, flex_wrap::FlexWrap, font_size::FontSize, font_style::FontStyle, font_weight::FontWeight, gap::Gap, item_align::ItemAlign, length_value::LengthValueProperty, letter_spacing::LetterSpacing, line_height::LineHeight, marin_padding::MarginPadding, max_size::MaxSizeProperty, normal::Normal, number::NumberProperty, opacity::Opacity, overflow::Overflow, pointer_events::PointerEvents, position::Position, size::SizeProperty, style_property_type::{string_to_css_property_type, CSSPropertyType}, style_value_type::{CssVariable, StyleValueType}, text_align::TextAlign, text_decoration::TextDecoration, text_overflow::TextOverflow, text_shadow::TextShadow, text_transform::TextTransform, transform::Transform, transform_origin::TransformOrigin, transition::Transition, unit::{generate_expr_by_length_value, Platform}, variable::Variable, vertical_align::VerticalAlign, visibility::Visibility, white_space::WhiteSpace, word_break::WordBreak
+    animation::Animation, animation_multi::AnimationMulti, aspect_ratio::AspectRatio, background::Background, background_image::BackgroundImage, background_position::BackgroundPosition, background_repeat::BackgroundRepeat, background_size::BackgroundSize, border::Border, border_color::BorderColor, border_radius::BorderRadius, border_style::BorderStyle, border_width::BorderWidth, box_orient::BoxOrient, box_shadow::BoxShadow, color::ColorProperty, container::Container, display::Display, expr::Expr, flex::Flex, flex_align::FlexAlign, flex_basis::FlexBasis, flex_direction::FlexDirection, flex_wrap::FlexWrap, font_size::FontSize, font_style::FontStyle, font_weight::FontWeight, gap::Gap, item_align::ItemAlign, length_value::LengthValueProperty, letter_spacing::LetterSpacing, line_height::LineHeight, marin_padding::MarginPadding, max_size::MaxSizeProperty, normal::Normal, number::NumberProperty, opacity::Opacity, overflow::Overflow, pointer_events::PointerEvents, position::Position, size::SizeProperty, style_property_type::{string_to_css_property_type, CSSPropertyType}, style_value_type::{CssVariable, StyleValueType}, text_align::TextAlign, text_decoration::TextDecoration, text_overflow::TextOverflow, text_shadow::TextShadow, text_transform::TextTransform, traits::ToStyleValue, transform::Transform, transform_origin::TransformOrigin, transition::Transition, unit::{generate_expr_by_length_value, Platform, PropertyTuple}, variable::Variable, vertical_align::VerticalAlign, visibility::Visibility, white_space::WhiteSpace, word_break::WordBreak
 
   },
-  utils::lowercase_first,
+  utils::{lowercase_first, to_camel_case},
 };
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropReason {
+  // 不支持的属性
+  UnknownProperty,
+  // 属性支持，但属性值无法转换
+  UnsupportedValue,
+  // 选择器无法转换，整条规则被丢弃
+  UnsupportedSelector,
+  // @container、@media 的条件无法在运行时求值，其中的规则被丢弃
+  UnsupportedQuery,
+}
+
+impl DropReason {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      DropReason::UnknownProperty => "unknown-property",
+      DropReason::UnsupportedValue => "unsupported-value",
+      DropReason::UnsupportedSelector => "unsupported-selector",
+      DropReason::UnsupportedQuery => "unsupported-query",
+    }
+  }
+}
+
+// 被丢弃的声明，index 为其在 properties 中的下标
+#[derive(Debug, Clone)]
+pub struct DroppedDeclaration {
+  pub index: usize,
+  pub property: String,
+  pub value: String,
+  pub reason: DropReason,
+}
+
 #[derive(Debug, Clone)]
 pub struct DeclsAndVars {
   pub decls: Vec<StyleValueType>,
   pub vars: Vec<CssVariable>,
-  pub has_env: bool
+  pub has_env: bool,
+  pub dropped: Vec<DroppedDeclaration>,
+  // decls 中每一项对应的 properties 下标
+  pub decl_indexes: Vec<usize>,
+}
+
+// 转换结果全部为无效值时，该声明会在输出时被丢弃
+fn is_invalid_style_value(style_value: &StyleValueType, platform: &Platform) -> bool {
+  match style_value.to_expr(platform.clone()) {
+    PropertyTuple::One(_, expr) => matches!(expr, ast::Expr::Invalid(_)),
+    PropertyTuple::Array(props) => {
+      !props.is_empty() && props.iter().all(|(_, expr)| matches!(expr, ast::Expr::Invalid(_)))
+    }
+    PropertyTuple::Variable(_, _) => false,
+  }
 }
 
-pub fn parse_style_properties(properties: &Vec<(String, Property)>) -> DeclsAndVars {
+fn dropped_declaration(index: usize, value: &Property, reason: DropReason) -> DroppedDeclaration {
+  DroppedDeclaration {
+    index,
+    property: value
+      .property_id()
+      .to_css_string(PrinterOptions::default())
+      .unwrap_or_default(),
+    value: value
+      .value_to_css_string(PrinterOptions::default())
+      .unwrap_or_default(),
+    reason,
+  }
+}
+
+// @supports 中的声明能否被转换，属性不支持或属性值无法转换时返回 false
+pub fn is_supported_declaration(property_id: &PropertyId, value: &str, platform: &Platform) -> bool {
+  let property = match Property::parse_string(property_id.clone(), value, ParserOptions::default()) {
+    Ok(property) => property,
+    Err(_) => return false,
+  };
+  let name = to_camel_case(
+    &property_id.to_css_string(PrinterOptions::default()).unwrap_or_default(),
+    false,
+  );
+  let decls_and_vars = parse_style_properties(&vec![(name, property)], platform);
+  decls_and_vars.dropped.is_empty() && (!decls_and_vars.decls.is_empty() || !decls_and_vars.vars.is_empty())
+}
+
+pub fn parse_style_properties(properties: &Vec<(String, Property)>, platform: &Platform) -> DeclsAndVars {
   let mut final_properties = vec![];
   let mut variable_properties = vec![];
+  let mut dropped = vec![];
+  let mut decl_indexes = vec![];
   let mut has_env = false;
-  for (id, value)  in properties.iter() {
+  for (index, (id, value)) in properties.iter().enumerate() {
+    // 上一条属性新增的 decls 都来自 index - 1
+    decl_indexes.resize(final_properties.len(), index.saturating_sub(1));
     let mut is_var: bool = false;
     let mut is_env: bool = false;
+    // css 变量声明不属于样式属性，不需要记录被丢弃的情况
+    let mut is_css_variable: bool = false;
     match value {
       Property::Unparsed(unparsed) => {
         // 检查是否包含 var() 函数
@@ -92,6 +172,7 @@ pub fn parse_style_properties(properties: &Vec<(String, Property)>) -> DeclsAndV
         let id_ = custom.name.to_css_string(Default::default()).unwrap();
         // css 变量
         if id_.starts_with("--") {
+          is_css_variable = true;
 
           let re = regex::Regex::new(r#"\b(\d+(?:px|vw|vh))\b"#).unwrap();
           let var_str = value.value_to_css_string(PrinterOptions::default()).unwrap().to_string();
@@ -133,6 +214,7 @@ pub fn parse_style_properties(properties: &Vec<(String, Property)>) -> DeclsAndV
     let mut property_name = property_name.to_string();
     lowercase_first(&mut property_name);
 
+    let decls_count = final_properties.len();
     match property_name.as_str() {
       // 基础样式
       "alignContent" => {
@@ -243,6 +325,12 @@ pub fn parse_style_properties(properties: &Vec<(String, Property)>) -> DeclsAndV
           value,
         ))));
       }
+      "containerType" | "containerName" | "container" => {
+        final_properties.push(StyleValueType::Container(Container::from((
+          id.to_string(),
+          value,
+        ))));
+      }
       "color" | "backgroundColor" => {
         final_properties.push(StyleValueType::ColorProperty(ColorProperty::from((
           id.to_string(),
@@ -602,13 +690,26 @@ pub fn parse_style_properties(properties: &Vec<(String, Property)>) -> DeclsAndV
       _ => {
         // position、zIndex等... 会自动处理 单位、数字等相关信息
         // final_properties.push(StyleValueType::Normal(Normal::new(id.to_string(), value.value_to_css_string(PrinterOptions::default()).unwrap())));
+        if !is_css_variable {
+          dropped.push(dropped_declaration(index, value, DropReason::UnknownProperty));
+        }
       }
     }
+    if !is_css_variable
+      && final_properties.len() > decls_count
+      && final_properties[decls_count..].iter().all(|style_value| is_invalid_style_value(style_value, platform))
+    {
+      dropped.push(dropped_declaration(index, value, DropReason::UnsupportedValue));
+    }
   }
 
+  decl_indexes.resize(final_properties.len(), properties.len().saturating_sub(1));
+
   DeclsAndVars {
     has_env: has_env,
     vars: variable_properties,
-    decls: final_properties
+    decls: final_properties,
+    dropped,
+    decl_indexes,
   }
 }
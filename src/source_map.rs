@@ -0,0 +1,111 @@
+use indexmap::IndexMap;
+use lightningcss::rules::Location;
+use serde_json::{json, Value};
+
+use crate::style_parser::{FontFaceItem, KeyFrameItem, RuleItem};
+use crate::style_propetries::style_container::StyleContainer;
+use crate::style_propetries::style_media::StyleMedia;
+use crate::style_propetries::unit::Platform;
+use crate::visitor::parse_style_value_locations;
+
+// 输出 styles、keyframes、medias、containers、fonts 与原始样式位置的映射，数组下标与 JsonWriter 的输出一一对应
+pub struct SourceMapWriter {
+  styles: Vec<RuleItem>,
+  keyframes: IndexMap<(u32, String), Vec<KeyFrameItem>>,
+  medias: Vec<StyleMedia>,
+  containers: Vec<StyleContainer>,
+  fonts: Vec<FontFaceItem>,
+  sources: Vec<String>,
+}
+
+impl SourceMapWriter {
+  pub fn new(
+    styles: Vec<RuleItem>,
+    keyframes: IndexMap<(u32, String), Vec<KeyFrameItem>>,
+    medias: Vec<StyleMedia>,
+    containers: Vec<StyleContainer>,
+    fonts: Vec<FontFaceItem>,
+    sources: Vec<String>,
+  ) -> Self {
+    Self {
+      styles,
+      keyframes,
+      medias,
+      containers,
+      fonts,
+      sources,
+    }
+  }
+
+  // locate 将 lightningcss 的位置转换为 (sources 下标, 行号, 列号)，行号和列号均从 1 开始
+  pub fn to_json<F>(&self, locate: F) -> String
+  where
+    F: Fn(&Location) -> (u32, u32, u32),
+  {
+    let location = |loc: &Location| {
+      let (source, line, column) = locate(loc);
+      json!([source, line, column])
+    };
+
+    let styles = self
+      .styles
+      .iter()
+      .map(|rule_item| {
+        // 声明的位置即其所在规则的位置，顺序与 declarations 的输出顺序一致
+        let declarations = parse_style_value_locations(
+          rule_item.declarations.clone(),
+          &rule_item.declaration_locations,
+          rule_item.important_declarections.clone(),
+          &rule_item.important_declaration_locations,
+          Platform::Harmony,
+        );
+        json!({
+          "loc": location(&rule_item.loc),
+          "declarations": declarations.iter().map(location).collect::<Vec<Value>>(),
+        })
+      })
+      .collect::<Vec<Value>>();
+
+    let keyframes = self
+      .keyframes
+      .values()
+      .map(|keyframe| {
+        keyframe
+          .first()
+          .map(|keyframe_item| location(&keyframe_item.loc))
+          .unwrap_or(Value::Null)
+      })
+      .collect::<Vec<Value>>();
+
+    let medias = self
+      .medias
+      .iter()
+      .map(|media| location(&media.loc))
+      .collect::<Vec<Value>>();
+
+    let fonts = self
+      .fonts
+      .iter()
+      .map(|font| location(&font.loc))
+      .collect::<Vec<Value>>();
+
+    let mut source_map = json!({
+      "version": 1,
+      "sources": self.sources,
+      "styles": styles,
+      "keyframes": keyframes,
+      "medias": medias,
+      "fonts": fonts,
+    });
+    // 与 JsonWriter 一致，存在 @container 时才输出 containers
+    if !self.containers.is_empty() {
+      source_map["containers"] = self
+        .containers
+        .iter()
+        .map(|container| location(&container.loc))
+        .collect::<Vec<Value>>()
+        .into();
+    }
+    source_map.to_string()
+  }
+}
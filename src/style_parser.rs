@@ -1,37 +1,45 @@
 use std::fmt::{Debug};
+use std::sync::{Arc, RwLock};
 use std::{cell::RefCell, convert::Infallible, rc::Rc};
 
 use super::parse_style_properties::parse_style_properties;
-use crate::constants::Pseudo;
-use crate::parse_style_properties::DeclsAndVars;
+use crate::constants::{AttributeOperator, Pseudo, SelectorType};
+use crate::parse_style_properties::{is_supported_declaration, DropReason, DroppedDeclaration};
 use crate::style_propetries::style_value_type::CssVariable;
-use crate::{generate_expr_enum, generate_expr_lit_str};
+use crate::{generate_expr_enum, generate_expr_lit_num, generate_expr_lit_str};
 use crate::style_propetries::font_weight::{self, FontWeight};
-use crate::style_propetries::style_property_enum::ArkUI_FontWeight;
+use crate::style_propetries::style_property_enum::{ArkUI_FontStyle, ArkUI_FontWeight};
 use crate::style_propetries::traits::ToExpr;
 use crate::visitor::parse_style_values;
 use crate::{
   style_propetries::{style_value_type::StyleValueType, unit::Platform},
-  utils::to_camel_case,
+  utils::{key_value_prop, to_camel_case, AttributeSelector, CompoundSelector, TSelector},
 };
 use indexmap::IndexMap;
 use lightningcss::properties::font::FontFamily;
-use lightningcss::rules::font_face::{FontFaceProperty, Source};
-use lightningcss::selector::PseudoElement;
+use lightningcss::media_query::MediaQuery;
+use lightningcss::rules::font_face::{FontFaceProperty, FontStyle as FontFaceStyle, Source};
+use lightningcss::selector::{PseudoClass, PseudoElement, SelectorList};
 use lightningcss::{
   declaration::DeclarationBlock,
+  error::{Error, ParserError},
   properties::{Property, font::{FontWeight as FontWeightProperty, AbsoluteFontWeight}},
-  rules::{keyframes::KeyframeSelector, CssRule},
-  stylesheet::{ParserOptions, PrinterOptions, StyleSheet},
-  traits::ToCss,
+  rules::{
+    import::ImportRule, keyframes::KeyframeSelector, layer::LayerName, supports::SupportsCondition, CssRule,
+    CssRuleList, Location,
+  },
+  stylesheet::{ParserFlags, ParserOptions, PrinterOptions, StyleSheet},
+  traits::{IntoOwned, ParseWithOptions, ToCss},
   visit_types,
   visitor::{Visit, VisitTypes, Visitor},
   selector::Component,
 };
-use parcel_selectors::parser::NthType;
+use parcel_selectors::attr::{AttrSelectorOperator, ParsedAttrSelectorOperation, ParsedCaseSensitivity};
+use parcel_selectors::parser::{Combinator, NthType};
 use swc_core::common::DUMMY_SP;
 use swc_core::ecma::ast::*;
 
+use crate::style_propetries::style_container::StyleContainer;
 use crate::style_propetries::style_media::StyleMedia;
 
 pub type StyleValue = Vec<StyleValueType>;
@@ -41,12 +49,8 @@ pub struct Selector {
   pub selector: String,
   pub is_pseudo: bool,
   pub pseudo_type: Option<Pseudo>,
-}
-
-impl Selector {
-  pub fn new(selector: String) -> Self {
-    Self { selector, is_pseudo: false, pseudo_type: None }
-  }
+  // 输出用的选择器结构，从右往左排列，如 .a > .b => ["b", Parent, "a"]
+  pub nesting_selector: Vec<TSelector>,
 }
 
 impl Debug for Selector {
@@ -68,7 +72,22 @@ pub struct RuleItem {
   pub declarations: Vec<StyleValueType>,
   pub important_declarections:  Vec<StyleValueType>,
   pub variables: Vec<CssVariable>,
-  pub has_env: bool
+  pub has_env: bool,
+  // 规则所在的位置
+  pub loc: Location,
+  // 选择器优先级，按 (id, class, tag) 各 10 位打包，可直接比较大小
+  pub specificity: u32,
+  // 规则在所有样式文件中的顺序，从 0 开始
+  pub order: u32,
+  // 层的优先级，从 0 开始，不在 @layer 中的样式最高
+  pub layer: u32,
+  // important 声明的层优先级，与 layer 的顺序相反，不在 @layer 中的样式最低
+  pub important_layer: u32,
+  // 所在 @container 的 id，0 表示不在 @container 中
+  pub container: u32,
+  // declarations、important_declarections 中每一项所在规则的位置
+  pub declaration_locations: Vec<Location>,
+  pub important_declaration_locations: Vec<Location>,
 }
 
 #[derive(Debug)]
@@ -76,7 +95,424 @@ pub struct StyleData {
   pub all_style: Rc<RefCell<Vec<RuleItem>>>, 
   pub all_keyframes: Rc<RefCell<IndexMap<(u32, String), Vec<KeyFrameItem>>>>,
   pub all_medias: Rc<RefCell<Vec<StyleMedia>>>,
+  pub all_containers: Rc<RefCell<Vec<StyleContainer>>>,
   pub all_fonts: Rc<RefCell<Vec<FontFaceItem>>>,
+  pub warnings: Vec<StyleWarning>,
+}
+
+// 转换时被丢弃的声明，line 从 1 开始，column 从 1 开始
+#[derive(Debug, Clone)]
+pub struct StyleWarning {
+  pub selector: String,
+  pub property: String,
+  pub value: String,
+  pub reason: DropReason,
+  pub source_index: u32,
+  pub line: u32,
+  pub column: u32,
+}
+
+impl StyleWarning {
+  fn new(selector: &str, dropped: &DroppedDeclaration, loc: &Location) -> Self {
+    StyleWarning {
+      selector: selector.to_string(),
+      property: dropped.property.clone(),
+      value: dropped.value.clone(),
+      reason: dropped.reason,
+      source_index: loc.source_index,
+      line: loc.line + 1,
+      column: loc.column,
+    }
+  }
+
+  pub fn message(&self) -> String {
+    match self.reason {
+      DropReason::UnknownProperty => format!("unknown property `{}` in `{}`", self.property, self.selector),
+      DropReason::UnsupportedValue => format!(
+        "unsupported value `{}` for property `{}` in `{}`",
+        self.value, self.property, self.selector
+      ),
+      DropReason::UnsupportedSelector => format!("unsupported selector `{}`", self.selector),
+      DropReason::UnsupportedQuery => format!("unsupported query `{}`", self.selector),
+    }
+  }
+
+  fn unsupported_selector(selector: &str, loc: &Location) -> Self {
+    Self::dropped_rule(selector, DropReason::UnsupportedSelector, loc)
+  }
+
+  fn unsupported_query(query: &str, loc: &Location) -> Self {
+    Self::dropped_rule(query, DropReason::UnsupportedQuery, loc)
+  }
+
+  // 整条规则被丢弃，selector 为规则的选择器或条件
+  fn dropped_rule(selector: &str, reason: DropReason, loc: &Location) -> Self {
+    StyleWarning {
+      selector: selector.to_string(),
+      property: String::new(),
+      value: String::new(),
+      reason,
+      source_index: loc.source_index,
+      line: loc.line + 1,
+      column: loc.column,
+    }
+  }
+}
+
+// 计算每个层的优先级，层按首次出现的顺序排列，子层位于父层之前，如 @layer a { @layer b {} } @layer c => a.b < a < c
+fn layer_ranks(layers: &[Vec<String>]) -> Vec<u32> {
+  let position = |layer: &[String]| layers.iter().position(|item| item == layer);
+  let mut indexes = (0..layers.len()).collect::<Vec<usize>>();
+  indexes.sort_by(|a, b| {
+    let (a, b) = (&layers[*a], &layers[*b]);
+    match (0..a.len().min(b.len())).find(|index| a[*index] != b[*index]) {
+      Some(index) => position(&a[..=index]).cmp(&position(&b[..=index])),
+      None => b.len().cmp(&a.len()),
+    }
+  });
+  let mut ranks = vec![0; layers.len()];
+  for (rank, index) in indexes.into_iter().enumerate() {
+    ranks[index] = rank as u32;
+  }
+  ranks
+}
+
+// 在编译时计算 @supports 的条件，声明按能否被转换判断，选择器按能否被转换判断，其余条件均不成立
+fn supports_condition(condition: &SupportsCondition<'_>, platform: &Platform) -> bool {
+  match condition {
+    SupportsCondition::Not(condition) => !supports_condition(condition, platform),
+    SupportsCondition::And(conditions) => conditions.iter().all(|condition| supports_condition(condition, platform)),
+    SupportsCondition::Or(conditions) => conditions.iter().any(|condition| supports_condition(condition, platform)),
+    SupportsCondition::Declaration { property_id, value } => is_supported_declaration(property_id, value, platform),
+    SupportsCondition::Selector(selector) => {
+      match SelectorList::parse_string_with_options(selector, ParserOptions::default()) {
+        Ok(selectors) => selectors
+          .0
+          .iter()
+          .all(|selector| expand_selector(selector).iter().all(is_supported_selector)),
+        Err(_) => false,
+      }
+    }
+    SupportsCondition::Unknown(_) => false,
+  }
+}
+
+// 检查选择器是否可以被转换，仅支持标签、通配符、id、类名、属性、:not()、伪类、后代、子及兄弟选择器，伪元素只能出现在最后一个复合选择器中
+fn is_supported_selector(selector: &lightningcss::selector::Selector<'_>) -> bool {
+  let mut iter = selector.iter();
+  let mut is_subject = true;
+  loop {
+    for component in &mut iter {
+      let supported = match component {
+        Component::LocalName(_) | Component::ID(_) | Component::Class(_) | Component::ExplicitUniversalType => true,
+        Component::AttributeInNoNamespaceExists { .. } | Component::AttributeInNoNamespace { .. } => true,
+        Component::AttributeOther(attribute) => attribute.namespace.is_none(),
+        Component::Negation(selectors) => selectors.iter().all(|selector| negated_compound(selector).is_some()),
+        Component::PseudoElement(PseudoElement::Before | PseudoElement::After) => is_subject,
+        Component::Nth(_) | Component::Empty | Component::NonTSPseudoClass(_) => component_pseudo(component).is_some(),
+        _ => false,
+      };
+      if !supported {
+        return false;
+      }
+    }
+    match iter.next_sequence() {
+      None => return true,
+      // ::before、::after 前会插入 PseudoElement 组合符，仍属于同一个复合选择器
+      Some(Combinator::PseudoElement) => {}
+      Some(Combinator::Child | Combinator::Descendant | Combinator::NextSibling | Combinator::LaterSibling) => {
+        is_subject = false
+      }
+      Some(_) => return false,
+    }
+  }
+}
+
+// 展开选择器中的 :is()、:where()，如 :is(.a, .b) .c => .a .c, .b .c
+// 无法展开时返回原选择器，由 is_supported_selector 报告
+fn expand_selector<'i>(selector: &lightningcss::selector::Selector<'i>) -> Vec<lightningcss::selector::Selector<'i>> {
+  let components = parse_order_components(selector);
+  if !components.iter().any(|component| matches!(component, Component::Is(_) | Component::Where(_))) {
+    return vec![selector.clone()];
+  }
+  match expand_components(components) {
+    Some(expanded) => expanded.into_iter().map(lightningcss::selector::Selector::from).collect(),
+    None => vec![selector.clone()],
+  }
+}
+
+// 按从左往右的顺序返回选择器的组件，iter_raw_parse_order_from 中复合选择器内部的顺序是反的
+fn parse_order_components<'i>(selector: &lightningcss::selector::Selector<'i>) -> Vec<Component<'i>> {
+  let mut compounds = vec![];
+  let mut iter = selector.iter();
+  loop {
+    let compound = (&mut iter).cloned().collect::<Vec<_>>();
+    let combinator = iter.next_sequence();
+    compounds.push((combinator, compound));
+    if combinator.is_none() {
+      break;
+    }
+  }
+  compounds
+    .into_iter()
+    .rev()
+    .flat_map(|(combinator, compound)| combinator.map(Component::Combinator).into_iter().chain(compound))
+    .collect()
+}
+
+// 伪元素前的 PseudoElement 组合符仍属于同一个复合选择器
+fn is_compound_boundary(component: &Component<'_>) -> bool {
+  matches!(component, Component::Combinator(combinator) if *combinator != Combinator::PseudoElement)
+}
+
+// 将嵌套规则选择器中的 & 替换为父选择器，返回 (选择器, 优先级, 是否包含伪元素)
+// 父选择器有多个时逐个替换，优先级按 & 等同于 :is(父选择器列表) 计算，即取父选择器中最高的优先级
+fn resolve_nesting<'i>(
+  selector: &lightningcss::selector::Selector<'i>,
+  parents: &[(lightningcss::selector::Selector<'i>, u32, bool)],
+) -> Vec<(lightningcss::selector::Selector<'i>, u32, bool)> {
+  if parents.is_empty() {
+    return vec![(selector.clone(), selector.specificity(), selector.has_pseudo_element())];
+  }
+  let mut components = parse_order_components(selector);
+  // 没有 & 时视为后代选择器，如 .title => & .title
+  if !components.iter().any(|component| matches!(component, Component::Nesting)) {
+    components.splice(0..0, [Component::Nesting, Component::Combinator(Combinator::Descendant)]);
+  }
+  let nesting_count = components
+    .iter()
+    .filter(|component| matches!(component, Component::Nesting))
+    .count() as u32;
+  // & 本身不计入优先级
+  let specificity = selector.specificity()
+    + nesting_count * parents.iter().map(|(_, specificity, _)| *specificity).max().unwrap_or(0);
+  parents
+    .iter()
+    .map(|(parent, _, has_pseudo_element)| {
+      let resolved = replace_nesting(components.clone(), &parse_order_components(parent));
+      (
+        lightningcss::selector::Selector::from(resolved),
+        specificity,
+        selector.has_pseudo_element() || *has_pseudo_element,
+      )
+    })
+    .collect()
+}
+
+// components、parent 按从左往右的顺序排列，父选择器最后一个复合选择器与 & 所在的复合选择器合并
+fn replace_nesting<'i>(mut components: Vec<Component<'i>>, parent: &[Component<'i>]) -> Vec<Component<'i>> {
+  let split = parent.iter().rposition(is_compound_boundary).map_or(0, |index| index + 1);
+  while let Some(position) = components.iter().position(|component| matches!(component, Component::Nesting)) {
+    let start = components[..position].iter().rposition(is_compound_boundary).map_or(0, |index| index + 1);
+    let end = components[position + 1..]
+      .iter()
+      .position(is_compound_boundary)
+      .map_or(components.len(), |index| position + 1 + index);
+    let mut compound = components[start..position].to_vec();
+    compound.extend(parent[split..].iter().cloned());
+    compound.extend(components[position + 1..end].iter().cloned());
+    // 标签选择器需位于复合选择器开头
+    compound.sort_by_key(|component| {
+      !matches!(component, Component::LocalName(_) | Component::ExplicitUniversalType)
+    });
+    let mut resolved = components[..start].to_vec();
+    resolved.extend(parent[..split].iter().cloned());
+    resolved.extend(compound);
+    resolved.extend(components[end..].iter().cloned());
+    components = resolved;
+  }
+  components
+}
+
+// components 按从左往右的顺序排列
+fn expand_components<'i>(components: Vec<Component<'i>>) -> Option<Vec<Vec<Component<'i>>>> {
+  let position = match components
+    .iter()
+    .position(|component| matches!(component, Component::Is(_) | Component::Where(_)))
+  {
+    Some(position) => position,
+    None => return Some(vec![components]),
+  };
+  let selectors = match &components[position] {
+    Component::Is(selectors) | Component::Where(selectors) => selectors.clone(),
+    _ => unreachable!(),
+  };
+  let start = components[..position].iter().rposition(is_compound_boundary).map_or(0, |index| index + 1);
+  let end = components[position + 1..]
+    .iter()
+    .position(is_compound_boundary)
+    .map_or(components.len(), |index| position + 1 + index);
+  let mut result = vec![];
+  for selector in selectors.iter() {
+    let inner = parse_order_components(selector);
+    let split = inner.iter().rposition(is_compound_boundary).map_or(0, |index| index + 1);
+    // 如 .a > .b:is(.c .d)，.b 同时需要满足两组祖先条件，无法用一个选择器表示
+    if split > 0 && start > 0 {
+      return None;
+    }
+    let mut compound = components[start..position].to_vec();
+    compound.extend(inner[split..].iter().cloned());
+    compound.extend(components[position + 1..end].iter().cloned());
+    // 标签选择器需位于复合选择器开头
+    compound.sort_by_key(|component| {
+      !matches!(component, Component::LocalName(_) | Component::ExplicitUniversalType)
+    });
+    let mut expanded = components[..start].to_vec();
+    expanded.extend(inner[..split].iter().cloned());
+    expanded.extend(compound);
+    expanded.extend(components[end..].iter().cloned());
+    result.extend(expand_components(expanded)?);
+  }
+  Some(result)
+}
+
+// :not() 中的选择器，仅支持由标签、id、类名、属性、伪类组成的单个复合选择器
+fn negated_compound(selector: &lightningcss::selector::Selector<'_>) -> Option<CompoundSelector> {
+  let mut compound = CompoundSelector::default();
+  let mut iter = selector.iter();
+  for component in &mut iter {
+    match component {
+      Component::LocalName(name) => compound.tag = Some(name.name.to_string()),
+      Component::ExplicitUniversalType => compound.tag = Some("*".to_string()),
+      Component::ID(id) => compound.id = Some(id.to_string()),
+      Component::Class(class) => compound.classes.push(class.to_string()),
+      Component::PseudoElement(_) => return None,
+      _ => match component_pseudo(component) {
+        Some(pseudo) => compound.pseudos.push(pseudo),
+        None => compound.attributes.push(attribute_selector(component)?),
+      },
+    }
+  }
+  match iter.next_sequence() {
+    None => Some(compound),
+    Some(_) => None,
+  }
+}
+
+// 根据解析后的选择器生成输出用的选择器结构，每个复合选择器转换为 CompoundSelector，伪类、伪元素记录在所在复合选择器的 pseudos 中
+fn nesting_selector(selector: &lightningcss::selector::Selector<'_>) -> Vec<TSelector> {
+  let mut result = vec![];
+  let mut compound = CompoundSelector::default();
+  let mut is_root = false;
+  let mut iter = selector.iter();
+  loop {
+    for component in &mut iter {
+      match component {
+        Component::LocalName(name) => compound.tag = Some(name.name.to_string()),
+        Component::ExplicitUniversalType => compound.tag = Some("*".to_string()),
+        Component::ID(id) => compound.id = Some(id.to_string()),
+        Component::Class(class) => compound.classes.push(class.to_string()),
+        Component::Root => is_root = true,
+        Component::Negation(selectors) => compound
+          .negations
+          .extend(selectors.iter().filter_map(negated_compound)),
+        // 其余组件已由 is_supported_selector 检查，无法转换的选择器不会走到这里
+        _ => {
+          if let Some(pseudo) = component_pseudo(component) {
+            compound.pseudos.push(pseudo);
+          } else if let Some(attribute) = attribute_selector(component) {
+            compound.attributes.push(attribute);
+          }
+        }
+      }
+    }
+    let combinator = match iter.next_sequence() {
+      // 伪元素仍属于同一个复合选择器
+      Some(Combinator::PseudoElement) => continue,
+      Some(Combinator::Child) => Some(SelectorType::Parent),
+      Some(Combinator::Descendant) => Some(SelectorType::Ancestor),
+      Some(Combinator::NextSibling) => Some(SelectorType::AdjacentSibling),
+      Some(Combinator::LaterSibling) => Some(SelectorType::GeneralSibling),
+      Some(_) => Some(SelectorType::Ancestor),
+      None => None,
+    };
+    let mut compound = std::mem::take(&mut compound);
+    // 伪元素在前一个组件序列中，调整到伪类之后，与书写顺序一致
+    compound.pseudos.sort_by_key(|pseudo| matches!(pseudo, Pseudo::Before | Pseudo::After));
+    // :root 用于声明全局变量，保持原有的字符串格式
+    if is_root && compound == CompoundSelector::default() {
+      result.push(TSelector::String(":root".to_string()));
+    } else {
+      result.push(TSelector::Compound(compound));
+    }
+    is_root = false;
+    match combinator {
+      Some(selector_type) => result.push(TSelector::Selector(selector_type)),
+      None => return result,
+    }
+  }
+}
+
+// 属性选择器，带命名空间的不支持
+fn attribute_selector(component: &Component<'_>) -> Option<AttributeSelector> {
+  let (name, operation) = match component {
+    Component::AttributeInNoNamespaceExists { local_name, .. } => (local_name, None),
+    Component::AttributeInNoNamespace { local_name, operator, value, case_sensitivity, .. } => {
+      (local_name, Some((operator, case_sensitivity, value)))
+    }
+    Component::AttributeOther(attribute) if attribute.namespace.is_none() => match &attribute.operation {
+      ParsedAttrSelectorOperation::Exists => (&attribute.local_name, None),
+      ParsedAttrSelectorOperation::WithValue { operator, case_sensitivity, expected_value } => {
+        (&attribute.local_name, Some((operator, case_sensitivity, expected_value)))
+      }
+    },
+    _ => return None,
+  };
+  Some(match operation {
+    None => AttributeSelector {
+      name: name.to_string(),
+      operator: AttributeOperator::Exists,
+      value: None,
+      case_insensitive: false,
+    },
+    Some((operator, case_sensitivity, value)) => AttributeSelector {
+      name: name.to_string(),
+      operator: match operator {
+        AttrSelectorOperator::Equal => AttributeOperator::Equal,
+        AttrSelectorOperator::Includes => AttributeOperator::Includes,
+        AttrSelectorOperator::DashMatch => AttributeOperator::DashMatch,
+        AttrSelectorOperator::Prefix => AttributeOperator::Prefix,
+        AttrSelectorOperator::Substring => AttributeOperator::Substring,
+        AttrSelectorOperator::Suffix => AttributeOperator::Suffix,
+      },
+      value: Some(value.to_string()),
+      case_insensitive: matches!(case_sensitivity, ParsedCaseSensitivity::AsciiCaseInsensitive),
+    },
+  })
+}
+
+// 支持的伪类、伪元素
+fn component_pseudo(component: &Component<'_>) -> Option<Pseudo> {
+  match component {
+    Component::Nth(nth) => match nth.ty {
+      NthType::Child if nth.is_function() => Some(Pseudo::NthChild(nth.a, nth.b, true)),
+      NthType::Child => Some(Pseudo::FirstChild),
+      NthType::LastChild if nth.is_function() => Some(Pseudo::NthChild(nth.a, nth.b, false)),
+      NthType::LastChild => Some(Pseudo::LastChild),
+      NthType::OfType if nth.is_function() => Some(Pseudo::NthOfType(nth.a, nth.b, true)),
+      NthType::OfType => Some(Pseudo::FirstOfType),
+      NthType::LastOfType if nth.is_function() => Some(Pseudo::NthOfType(nth.a, nth.b, false)),
+      NthType::LastOfType => Some(Pseudo::LastOfType),
+      NthType::OnlyChild => Some(Pseudo::OnlyChild),
+      _ => None,
+    },
+    Component::Empty => Some(Pseudo::Empty),
+    Component::NonTSPseudoClass(pseudo_class) => state_pseudo(pseudo_class),
+    Component::PseudoElement(PseudoElement::Before) => Some(Pseudo::Before),
+    Component::PseudoElement(PseudoElement::After) => Some(Pseudo::After),
+    _ => None,
+  }
+}
+
+// 交互状态伪类，对应 ArkUI 的 stateStyles
+fn state_pseudo(pseudo_class: &PseudoClass) -> Option<Pseudo> {
+  match pseudo_class {
+    PseudoClass::Active => Some(Pseudo::Active),
+    PseudoClass::Focus => Some(Pseudo::Focus),
+    PseudoClass::Disabled => Some(Pseudo::Disabled),
+    PseudoClass::Hover => Some(Pseudo::Hover),
+    _ => None,
+  }
 }
 
 pub struct KeyFramesData {
@@ -88,6 +524,7 @@ pub struct KeyFramesData {
 pub struct KeyFrameItem {
   pub percentage: f32,
   pub declarations: Vec<StyleValueType>,
+  pub loc: Location,
 }
 impl KeyFrameItem {
   pub fn to_expr(&self) -> Vec<PropOrSpread> {
@@ -111,11 +548,52 @@ impl KeyFrameItem {
 #[derive(Debug, Clone)]
 pub struct FontFaceItem {
   pub font_family: String,
+  // 第一个 url 来源，供只读取 src 的运行时使用
   pub src: String,
+  // src 中的所有来源，按声明顺序排列
+  pub sources: Vec<FontSource>,
+  pub font_style: Option<ArkUI_FontStyle>,
   pub font_weight: Option<ArkUI_FontWeight>,
+  // font-weight 的范围 (最小值, 最大值)，单个值时两者相同
+  pub font_weight_range: Option<(u16, u16)>,
+  // unicode-range 中的范围 (起点, 终点)，均包含在内
+  pub unicode_range: Vec<(u32, u32)>,
+  pub font_display: Option<String>,
+  pub loc: Location,
+}
+
+// src 中的一项，url() 或 local()
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+  Url { url: String, format: Option<String> },
+  Local(String),
+}
+
+impl FontSource {
+  pub fn to_expr(&self) -> Expr {
+    let props = match self {
+      FontSource::Url { url, format } => {
+        let mut props = vec![key_value_prop("url", generate_expr_lit_str!(url.clone()))];
+        if let Some(format) = format {
+          props.push(key_value_prop("format", generate_expr_lit_str!(format.clone())));
+        }
+        props
+      }
+      FontSource::Local(local) => vec![key_value_prop("local", generate_expr_lit_str!(local.clone()))],
+    };
+    Expr::Object(ObjectLit { span: DUMMY_SP, props })
+  }
 }
 
 impl FontFaceItem {
+  // 同一字体族的多个字体按样式、字重、字符范围区分，均相同时后出现的覆盖先出现的
+  fn is_same_face(&self, other: &FontFaceItem) -> bool {
+    self.font_family == other.font_family
+      && self.font_style == other.font_style
+      && self.font_weight_range == other.font_weight_range
+      && self.unicode_range == other.unicode_range
+  }
+
   pub fn to_expr(&self) -> Vec<PropOrSpread> {
     let mut result = vec![
       PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
@@ -126,47 +604,348 @@ impl FontFaceItem {
         key: PropName::Ident(Ident::new("src".into(), DUMMY_SP)),
         value: Box::new(generate_expr_lit_str!(self.src.clone())),
       }))),
+      key_value_prop("sources", array_expr(self.sources.iter().map(FontSource::to_expr))),
     ];
+    if let Some(font_style) = self.font_style {
+      result.push(key_value_prop("fontStyle", generate_expr_enum!(font_style)));
+    }
     if let Some(font_weight) = self.font_weight {
       result.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
         key: PropName::Ident(Ident::new("fontWeight".into(), DUMMY_SP)),
         value: Box::new(generate_expr_enum!(font_weight)),
       }))));
     }
+    if let Some((min, max)) = self.font_weight_range {
+      result.push(key_value_prop(
+        "fontWeightRange",
+        array_expr([min, max].into_iter().map(|weight| generate_expr_lit_num!(weight as f64))),
+      ));
+    }
+    if !self.unicode_range.is_empty() {
+      result.push(key_value_prop(
+        "unicodeRange",
+        array_expr(self.unicode_range.iter().map(|(start, end)| {
+          array_expr([*start, *end].into_iter().map(|value| generate_expr_lit_num!(value as f64)))
+        })),
+      ));
+    }
+    if let Some(font_display) = &self.font_display {
+      result.push(key_value_prop("fontDisplay", generate_expr_lit_str!(font_display.clone())));
+    }
     return result;
   }
 }
 
+fn array_expr(elems: impl Iterator<Item = Expr>) -> Expr {
+  Expr::Array(ArrayLit {
+    span: DUMMY_SP,
+    elems: elems
+      .map(|expr| Some(ExprOrSpread { spread: None, expr: Box::new(expr) }))
+      .collect(),
+  })
+}
+
+// font-weight 的数值，bolder、lighter 在 @font-face 中无效，按 normal 处理
+fn absolute_font_weight(font_weight: &FontWeightProperty) -> f32 {
+  match font_weight {
+    FontWeightProperty::Absolute(AbsoluteFontWeight::Weight(num)) => *num,
+    FontWeightProperty::Absolute(AbsoluteFontWeight::Bold) => 700.0,
+    _ => 400.0,
+  }
+}
+
+// 样式解析错误，line 从 1 开始，column 从 1 开始
+#[derive(Debug, Clone)]
+pub struct StyleParseError {
+  pub message: String,
+  pub source_index: u32,
+  pub line: u32,
+  pub column: u32,
+}
+
+impl StyleParseError {
+  fn new(message: String, loc: &Location) -> Self {
+    StyleParseError {
+      message,
+      source_index: loc.source_index,
+      line: loc.line + 1,
+      column: loc.column,
+    }
+  }
+
+  fn from_error(error: &Error<ParserError>, source_index: u32) -> Self {
+    let (line, column) = match &error.loc {
+      Some(loc) => (loc.line + 1, loc.column),
+      None => (0, 0),
+    };
+    StyleParseError {
+      message: error.kind.to_string(),
+      source_index,
+      line,
+      column,
+    }
+  }
+}
+
+// 样式文件，在 StyleParser::sources 中的下标即 source_index
+#[derive(Debug, Clone)]
+pub struct StyleSource {
+  pub filename: String,
+  // 通过 @import 引入时，@import 规则所在的位置
+  pub importer: Option<Location>,
+}
+
+// 解析 @import，参数为 @import 的地址和所在的文件名，返回 (文件名, 样式内容)，无法读取时返回错误信息
+pub type ImportResolver<'a> = dyn Fn(&str, &str) -> Result<(String, String), String> + 'a;
+
+fn parser_options<'o, 'a>(
+  filename: &str,
+  source_index: u32,
+  warnings: &Arc<RwLock<Vec<Error<ParserError<'a>>>>>,
+) -> ParserOptions<'o, 'a> {
+  ParserOptions {
+    filename: filename.to_string(),
+    source_index,
+    error_recovery: true,
+    // 开启原生嵌套，嵌套规则展开为普通规则
+    flags: ParserFlags::NESTING,
+    warnings: Some(Arc::clone(warnings)),
+    ..ParserOptions::default()
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct StyleDeclaration<'i> {
   pub specificity: u32,
+  // 规则在所有样式文件中的顺序
+  pub order: u32,
+  // 所在层在 layers 中的下标，不在 @layer 中时为 None
+  pub layer: Option<usize>,
+  // 所在 @container 的 id，0 表示不在 @container 中
+  pub container: u32,
   pub declaration: DeclarationBlock<'i>,
+  pub loc: Location,
+}
+
+// (media, 选择器, 合并后的声明, 普通属性所在位置, important 属性所在位置)
+type StyleRecord<'i> = (u32, Selector, StyleDeclaration<'i>, Vec<Location>, Vec<Location>);
+
+// (media, 选择器, 声明) 及 (media, 动画名, 关键帧)
+type SharedStyles<'i> = Rc<RefCell<Vec<(u32, Selector, Vec<StyleDeclaration<'i>>)>>>;
+type SharedKeyframes = Rc<RefCell<Vec<(u32, String, Vec<KeyFrameItem>)>>>;
+
+// StyleParser 与 StyleVisitor 共享的解析结果及配置，多个样式文件的解析结果合并在一起
+struct StyleContext<'i> {
+  all_style: SharedStyles<'i>,
+  keyframes: SharedKeyframes,
+  all_fonts: Rc<RefCell<Vec<FontFaceItem>>>,
+  medias: Rc<RefCell<Vec<StyleMedia>>>,
+  containers: Rc<RefCell<Vec<StyleContainer>>>,
+  warnings: Rc<RefCell<Vec<StyleWarning>>>,
+  sources: Rc<RefCell<Vec<StyleSource>>>,
+  import_resolver: Option<Rc<ImportResolver<'i>>>,
+  layers: Rc<RefCell<Vec<Vec<String>>>>,
+  platform: Platform,
 }
 
 struct StyleVisitor<'i> {
-  all_style: Rc<RefCell<Vec<(u32, Selector, Vec<StyleDeclaration<'i>>)>>>,
-  keyframes: Rc<RefCell<Vec<(u32, String, Vec<KeyFrameItem>)>>>,
+  all_style: SharedStyles<'i>,
+  keyframes: SharedKeyframes,
   all_fonts: Rc<RefCell<Vec<FontFaceItem>>>,
   medias: Rc<RefCell<Vec<StyleMedia>>>,
+  containers: Rc<RefCell<Vec<StyleContainer>>>,
+  warnings: Rc<RefCell<Vec<StyleWarning>>>,
   media_index: u32,
+  container_index: u32,
+  // 下一条样式规则的顺序，跨文件累加
+  rule_order: u32,
+  // 嵌套规则的父选择器，已替换 &，(选择器, 优先级, 是否包含伪元素)
+  parent_selectors: Vec<(lightningcss::selector::Selector<'i>, u32, bool)>,
+  sources: Rc<RefCell<Vec<StyleSource>>>,
+  import_resolver: Option<Rc<ImportResolver<'i>>>,
+  // 正在解析的文件，用于检测循环 @import
+  import_stack: Vec<String>,
+  // 解析 @import 时产生的错误
+  errors: Vec<StyleParseError>,
+  // 所有层的完整名称，按首次出现的顺序排列，如 @layer a { @layer b {} } => [["a"], ["a", "b"]]
+  layers: Rc<RefCell<Vec<Vec<String>>>>,
+  // 当前所在层的完整名称
+  layer: Vec<String>,
+  platform: Platform,
 }
 
 impl<'i> StyleVisitor<'i> {
-  pub fn new(
-    all_style: Rc<RefCell<Vec<(u32, Selector, Vec<StyleDeclaration<'i>>)>>>,
-    keyframes: Rc<RefCell<Vec<(u32, String, Vec<KeyFrameItem>)>>>,
-    all_fonts: Rc<RefCell<Vec<FontFaceItem>>>,
-    medias: Rc<RefCell<Vec<StyleMedia>>>,
-    media_index: u32,
-  ) -> Self {
+  pub fn new(context: StyleContext<'i>, media_index: u32, rule_order: u32, import_stack: Vec<String>) -> Self {
+    let StyleContext {
+      all_style,
+      keyframes,
+      all_fonts,
+      medias,
+      containers,
+      warnings,
+      sources,
+      import_resolver,
+      layers,
+      platform,
+    } = context;
     StyleVisitor {
       all_style,
       keyframes,
       all_fonts,
       medias,
+      containers,
+      warnings,
       media_index,
+      container_index: 0,
+      rule_order,
+      parent_selectors: vec![],
+      sources,
+      import_resolver,
+      import_stack,
+      errors: vec![],
+      layers,
+      layer: vec![],
+      platform,
     }
   }
+
+  // 记录层并返回其完整名称，父层先于子层记录，匿名层使用不会与其他层重名的名称
+  fn register_layer(&mut self, name: Option<&LayerName<'_>>) -> Vec<String> {
+    let mut layers = self.layers.borrow_mut();
+    let mut layer = self.layer.clone();
+    match name {
+      Some(name) => {
+        for segment in name.0.iter() {
+          layer.push(segment.to_string());
+          if !layers.contains(&layer) {
+            layers.push(layer.clone());
+          }
+        }
+      }
+      None => {
+        layer.push(format!("@anonymous-{}", layers.len()));
+        layers.push(layer.clone());
+      }
+    }
+    layer
+  }
+
+  // 在 layer 中访问规则，结束后恢复当前所在层
+  fn visit_layer_rules(&mut self, layer: Vec<String>, rules: &mut CssRuleList<'i>) {
+    let parent_layer = std::mem::replace(&mut self.layer, layer);
+    let _ = self.visit_rule_list(rules);
+    self.layer = parent_layer;
+  }
+
+  fn layer_index(&self) -> Option<usize> {
+    if self.layer.is_empty() {
+      return None;
+    }
+    self.layers.borrow().iter().position(|layer| *layer == self.layer)
+  }
+
+  // 记录 @media 并返回其 id，嵌套时与外层的条件以 and 合并，条件相同的 @media 共用同一个 id
+  // 无法求值的查询输出警告，全部无法求值时返回 None，其中的规则不输出
+  fn register_media(&mut self, media_queries: &Vec<MediaQuery<'i>>, loc: Location) -> Option<u32> {
+    let mut media_data = StyleMedia::new(0, vec![], loc);
+    let unsupported = media_data.parse(media_queries);
+    for index in unsupported.iter() {
+      let query = media_queries[*index].to_css_string(PrinterOptions::default()).unwrap_or_default();
+      self
+        .warnings
+        .borrow_mut()
+        .push(StyleWarning::unsupported_query(&format!("@media {}", query), &loc));
+    }
+    if unsupported.len() == media_queries.len() {
+      return None;
+    }
+    let mut medias = self.medias.borrow_mut();
+    if self.media_index > 0 {
+      let outer = medias[self.media_index as usize - 1].conditions.clone();
+      media_data.conditions = StyleMedia::combine(outer, media_data.conditions);
+    }
+    if let Some(media) = medias.iter().find(|media| media.conditions == media_data.conditions) {
+      return Some(media.media_id);
+    }
+    media_data.media_id = medias.len() as u32 + 1;
+    medias.push(media_data);
+    Some(medias.len() as u32)
+  }
+
+  // 读取并解析 @import 的文件，文件中的规则按 @import 所在位置合并，带媒体查询时记录为新的 media
+  fn visit_import(&mut self, import_rule: &ImportRule<'i>) {
+    // 未设置 import_resolver 时忽略 @import
+    let import_resolver = match &self.import_resolver {
+      Some(import_resolver) => Rc::clone(import_resolver),
+      None => return,
+    };
+    if let Some(supports) = &import_rule.supports {
+      if !supports_condition(supports, &self.platform) {
+        return;
+      }
+    }
+    let importer = self.import_stack.last().cloned().unwrap_or_default();
+    let (filename, code) = match import_resolver(&import_rule.url, &importer) {
+      Ok(result) => result,
+      Err(message) => {
+        self.errors.push(StyleParseError::new(message, &import_rule.loc));
+        return;
+      }
+    };
+    if self.import_stack.contains(&filename) {
+      self.errors.push(StyleParseError::new(
+        format!("circular @import of `{}`", import_rule.url),
+        &import_rule.loc,
+      ));
+      return;
+    }
+
+    let source_index = {
+      let mut sources = self.sources.borrow_mut();
+      sources.push(StyleSource {
+        filename: filename.clone(),
+        importer: Some(import_rule.loc),
+      });
+      sources.len() as u32 - 1
+    };
+    let warnings = Arc::new(RwLock::new(vec![]));
+    let result = StyleSheet::parse(&code, parser_options(&filename, source_index, &warnings));
+    if let Ok(warnings) = warnings.read() {
+      self.errors.extend(
+        warnings
+          .iter()
+          .map(|error| StyleParseError::from_error(error, source_index)),
+      );
+    }
+    let mut rules = match result {
+      // 文件内容在解析完成后释放，规则需转换为不依赖原始字符串的版本
+      Ok(stylesheet) => stylesheet.rules.into_owned(),
+      Err(error) => {
+        self.errors.push(StyleParseError::from_error(&error, source_index));
+        return;
+      }
+    };
+
+    let media_index = self.media_index;
+    if !import_rule.media.media_queries.is_empty() {
+      match self.register_media(&import_rule.media.media_queries, import_rule.loc) {
+        Some(index) => self.media_index = index,
+        None => return,
+      }
+    }
+    self.import_stack.push(filename);
+    match &import_rule.layer {
+      Some(name) => {
+        let layer = self.register_layer(name.as_ref());
+        self.visit_layer_rules(layer, &mut rules);
+      }
+      None => {
+        let _ = self.visit_rule_list(&mut rules);
+      }
+    }
+    self.import_stack.pop();
+    self.media_index = media_index;
+  }
 }
 
 // 收集所有的样式到 all_style 中，以元祖的形式存在 (selector, vec[declaration1, declaration2, ...])
@@ -177,99 +956,158 @@ impl<'i> Visitor<'i> for StyleVisitor<'i> {
     match rule {
       // 属性规则收集
       CssRule::Style(style) => {
-        let selectors = style.selectors.0.iter().map(|selector| {
+        // 嵌套规则先将 & 替换为父选择器
+        let resolved = style
+          .selectors
+          .0
+          .iter()
+          .flat_map(|selector| resolve_nesting(selector, &self.parent_selectors))
+          .collect::<Vec<_>>();
+        let selectors = resolved.iter().flat_map(|(selector, specificity, has_pseudo_element)| {
+          // :is()、:where() 展开为多个选择器，优先级沿用原选择器
+          let specificity = *specificity;
+          let has_pseudo_element = *has_pseudo_element;
+          expand_selector(selector)
+            .into_iter()
+            .map(move |selector| (selector, specificity, has_pseudo_element))
+        }).filter_map(|(selector, specificity, has_pseudo_element)| {
+          let selector = &selector;
           let mut selector_str = selector.to_css_string(PrinterOptions::default()).unwrap();
+          // 无法转换的选择器不输出，避免只保留部分组件后匹配到更多的元素，如 .a:checked => .a
+          if !is_supported_selector(selector) {
+            self
+              .warnings
+              .borrow_mut()
+              .push(StyleWarning::unsupported_selector(&selector_str, &style.loc));
+            return None;
+          }
           let mut is_pseudo = false;
           let mut pseudo_type = None;
-          if selector.has_pseudo_element() {
+          if has_pseudo_element {
             is_pseudo = true;
           }
           selector.iter().for_each(|component| {
-            match component {
-              Component::Nth(nth) => {
-                match nth.ty {
-                  NthType::Child => {
-                    is_pseudo = true;
-                    if nth.is_function() {
-                      pseudo_type = Some(Pseudo::NthChild(nth.a, nth.b, true));
-                    } else {
-                      pseudo_type = Some(Pseudo::FirstChild);
-                    }
-                  },
-                  NthType::LastChild => {
-                    is_pseudo = true;
-                    if nth.is_function() {
-                      pseudo_type = Some(Pseudo::NthChild(nth.a, nth.b, false));
-                    } else {
-                      pseudo_type = Some(Pseudo::LastChild);
-                    }
-                  },
-                  _ => {}
-                }
-              },
-              Component::Empty => {
-                is_pseudo = true;
-                pseudo_type = Some(Pseudo::Empty);
-              },
-              Component::PseudoElement(pseudo) => {
-                match pseudo {
-                  PseudoElement::After => {
-                    pseudo_type = Some(Pseudo::After);
-                    is_pseudo = true;
-                  },
-                  PseudoElement::Before => {
-                    pseudo_type = Some(Pseudo::Before);
-                    is_pseudo = true;
-                  },
-                  _ => {}
-                }
-              },
-              _ => {}
+            if let Some(pseudo) = component_pseudo(component) {
+              is_pseudo = true;
+              pseudo_type = Some(pseudo);
             }
           });
-          Selector {
-            selector: selector_str,
-            is_pseudo,
-            pseudo_type,
-          }
-        }).collect::<Vec<Selector>>();
+          Some((
+            Selector {
+              selector: selector_str,
+              is_pseudo,
+              pseudo_type,
+              nesting_selector: nesting_selector(selector),
+            },
+            specificity,
+          ))
+        }).collect::<Vec<(Selector, u32)>>();
+        let order = self.rule_order;
+        self.rule_order += 1;
+        let layer = self.layer_index();
 
-        for (index, selector) in selectors.iter().enumerate() {
+        // 只包含嵌套规则的父规则不输出
+        let has_declarations = !style.declarations.declarations.is_empty()
+          || !style.declarations.important_declarations.is_empty()
+          || style.rules.0.is_empty();
+        for (selector, specificity) in selectors.iter().filter(|_| has_declarations) {
           let mut all_style = self.all_style.borrow_mut();
           let decorations = all_style
             .iter_mut()
-            .find(|(media_idx, id, _)| id == selector && media_idx == &self.media_index);
+            .find(|(media_idx, id, declarations)| {
+              id == selector
+                && declarations[0].order == order
+                && media_idx == &self.media_index
+                && declarations[0].layer == layer
+                && declarations[0].container == self.container_index
+            });
           if let Some((_, _, declarations)) = decorations {
             declarations.push(StyleDeclaration {
-              specificity: style.selectors.0.get(index).unwrap().specificity(),
+              specificity: *specificity,
+              order,
+              layer,
+              container: self.container_index,
               declaration: style.declarations.clone(),
+              loc: style.loc,
             });
           } else {
             all_style.push((
               self.media_index,
               selector.clone(),
               vec![StyleDeclaration {
-                specificity: style.selectors.0.get(index).unwrap().specificity(),
+                specificity: *specificity,
+                order,
+                layer,
+                container: self.container_index,
                 declaration: style.declarations.clone(),
+                loc: style.loc,
               }],
             ));
           }
         }
+
+        if !style.rules.0.is_empty() {
+          let parent_selectors = std::mem::replace(&mut self.parent_selectors, resolved);
+          let _ = self.visit_rule_list(&mut style.rules);
+          self.parent_selectors = parent_selectors;
+        }
+      }
+      CssRule::Import(import_rule) => self.visit_import(import_rule),
+      // 层中的规则按普通规则输出，层的优先级记录在 layer 中
+      CssRule::LayerStatement(layer_statement) => {
+        for name in layer_statement.names.iter() {
+          self.register_layer(Some(name));
+        }
+      }
+      CssRule::LayerBlock(layer_block) => {
+        let layer = self.register_layer(layer_block.name.as_ref());
+        self.visit_layer_rules(layer, &mut layer_block.rules);
+      }
+      // 条件成立时保留其中的规则
+      CssRule::Supports(supports) if supports_condition(&supports.condition, &self.platform) => {
+        let _ = self.visit_rule_list(&mut supports.rules);
       }
       // media
       CssRule::Media(media) => {
-        //let mut medias = self.medias.borrow_mut();
-        let media_id = self.medias.borrow_mut().len() as u32 + 1;
-        let mut media_data = StyleMedia {
-          media_id,
-          conditions: vec![],
-        };
-        media_data.parse(&media.query.media_queries);
+        if let Some(index) = self.register_media(&media.query.media_queries, media.loc) {
+          let media_index = std::mem::replace(&mut self.media_index, index);
+          let _ = self.visit_rule_list(&mut media.rules);
+          self.media_index = media_index;
+        }
+      }
+      // 容器查询，其中的样式记录所在的 @container，嵌套时记录外层 @container
+      CssRule::Container(container) => {
+        let container_id = self.containers.borrow().len() as u32 + 1;
+        let mut container_data = StyleContainer::new(
+          container_id,
+          container.name.as_ref(),
+          self.container_index,
+          container.loc,
+        );
+        // 样式查询 style() 等无法求值的条件不输出，避免其中的规则无条件生效
+        if !container_data.parse(&container.condition) {
+          let query = match &container.name {
+            Some(name) => format!(
+              "@container {} {}",
+              name.to_css_string(PrinterOptions::default()).unwrap_or_default(),
+              container.condition.to_css_string(PrinterOptions::default()).unwrap_or_default()
+            ),
+            None => format!(
+              "@container {}",
+              container.condition.to_css_string(PrinterOptions::default()).unwrap_or_default()
+            ),
+          };
+          self
+            .warnings
+            .borrow_mut()
+            .push(StyleWarning::unsupported_query(&query, &container.loc));
+          return Ok(());
+        }
+        self.containers.borrow_mut().push(container_data);
 
-        self.medias.borrow_mut().push(media_data);
-        self.media_index = self.medias.borrow_mut().len() as u32;
-        let _ = self.visit_rule_list(&mut media.rules);
-        self.media_index = 0;
+        let container_index = std::mem::replace(&mut self.container_index, container_id);
+        let _ = self.visit_rule_list(&mut container.rules);
+        self.container_index = container_index;
       }
       // 动画收集
       CssRule::Keyframes(keyframes_rule) => {
@@ -285,32 +1123,42 @@ impl<'i> Visitor<'i> for StyleVisitor<'i> {
           .clone()
           .into_iter()
           .for_each(|keyframe| {
-            keyframe.selectors.into_iter().for_each(|selector| {
-              let properties = keyframe
-                .declarations
+            let properties = keyframe
+              .declarations
+              .iter()
+              .map(|property| {
+                (
+                  to_camel_case(
+                    property
+                      .0
+                      .property_id()
+                      .to_css_string(PrinterOptions::default())
+                      .unwrap()
+                      .as_str(),
+                    false,
+                  ),
+                  property.0.clone(),
+                )
+              })
+              .collect::<Vec<(_, _)>>(); // Speci
+            let decls_and_vars = parse_style_properties(&properties, &self.platform);
+            // 每个关键帧的声明只解析一次，from, to {} 这样的多个选择器不会重复警告
+            let keyframe_selector = format!("@keyframes {}", keyframe_data.name);
+            self.warnings.borrow_mut().extend(
+              decls_and_vars
+                .dropped
                 .iter()
-                .map(|property| {
-                  (
-                    to_camel_case(
-                      property
-                        .0
-                        .property_id()
-                        .to_css_string(PrinterOptions::default())
-                        .unwrap()
-                        .as_str(),
-                      false,
-                    ),
-                    property.0.clone(),
-                  )
-                })
-                .collect::<Vec<(_, _)>>(); // Speci
+                .map(|dropped| StyleWarning::new(&keyframe_selector, dropped, &keyframes_rule.loc)),
+            );
+            keyframe.selectors.into_iter().for_each(|selector| {
               let keyframe_item = KeyFrameItem {
                 percentage: match selector {
                   KeyframeSelector::Percentage(percentage) => percentage.0,
                   KeyframeSelector::From => 0.0,
                   KeyframeSelector::To => 1.0,
                 },
-                declarations: parse_style_properties(&properties).decls,
+                declarations: decls_and_vars.decls.clone(),
+                loc: keyframes_rule.loc,
               };
 
               keyframe_data.keyframes.push(keyframe_item)
@@ -333,7 +1181,13 @@ impl<'i> Visitor<'i> for StyleVisitor<'i> {
         let mut font_face = FontFaceItem {
           font_family: "".to_string(),
           src: "".to_string(),
+          sources: vec![],
+          font_style: None,
           font_weight: None,
+          font_weight_range: None,
+          unicode_range: vec![],
+          font_display: None,
+          loc: font_face_rule.loc,
         };
         font_face_rule.properties.iter().for_each(|property| {
           match property {
@@ -341,17 +1195,37 @@ impl<'i> Visitor<'i> for StyleVisitor<'i> {
               font_face.font_family = value.to_css_string(PrinterOptions::default()).unwrap();
             },
             FontFaceProperty::Source(source) => {
-              // src 只取第一个
-              if let Some(next) = source.iter().next() {
-                match next {
-                  Source::Url(value) => {
-                    font_face.src = value.url.url.as_ref().to_string();
-                  },
-                  _ => {}
-                }
-              }
+              font_face.sources = source.iter().map(|source| match source {
+                Source::Url(value) => FontSource::Url {
+                  url: value.url.url.as_ref().to_string(),
+                  // format() 输出为带引号的字符串，去掉引号
+                  format: value
+                    .format
+                    .as_ref()
+                    .and_then(|format| format.to_css_string(PrinterOptions::default()).ok())
+                    .map(|format| format.trim_matches('"').to_string()),
+                },
+                Source::Local(family) => FontSource::Local(
+                  family.to_css_string(PrinterOptions::default()).unwrap_or_default(),
+                ),
+              }).collect();
+              // src 取第一个 url
+              font_face.src = font_face.sources.iter().find_map(|source| match source {
+                FontSource::Url { url, .. } => Some(url.clone()),
+                FontSource::Local(_) => None,
+              }).unwrap_or_default();
+            },
+            FontFaceProperty::FontStyle(font_style) => {
+              font_face.font_style = Some(match font_style {
+                FontFaceStyle::Normal => ArkUI_FontStyle::ARKUI_FONT_STYLE_NORMAL,
+                // ArkUI 没有 oblique，按 italic 处理
+                FontFaceStyle::Italic | FontFaceStyle::Oblique(_) => ArkUI_FontStyle::ARKUI_FONT_STYLE_ITALIC,
+              });
             },
             FontFaceProperty::FontWeight(font_weight) => {
+              let min = absolute_font_weight(&font_weight.0);
+              let max = absolute_font_weight(&font_weight.1);
+              font_face.font_weight_range = Some((min.min(max) as u16, min.max(max) as u16));
               font_face.font_weight = Some(match &font_weight.0 {
                 FontWeightProperty::Bolder => ArkUI_FontWeight::ARKUI_FONT_WEIGHT_BOLDER,
                 FontWeightProperty::Lighter => ArkUI_FontWeight::ARKUI_FONT_WEIGHT_LIGHTER,
@@ -378,20 +1252,31 @@ impl<'i> Visitor<'i> for StyleVisitor<'i> {
                 },
               });
             },
+            FontFaceProperty::UnicodeRange(unicode_range) => {
+              font_face.unicode_range = unicode_range.iter().map(|range| (range.start, range.end)).collect();
+            },
+            // font-display 不在 lightningcss 支持的描述符中，作为自定义属性解析
+            FontFaceProperty::Custom(custom) if custom.name.as_ref() == "font-display" => {
+              font_face.font_display = Property::Custom(custom.clone())
+                .value_to_css_string(PrinterOptions::default())
+                .ok()
+                .map(|value| value.trim().to_string());
+            },
             _ => {}
           };
-          if !font_face.font_family.is_empty() && !font_face.src.is_empty() {
-            let mut all_fonts = self.all_fonts.borrow_mut();
-            let has_font_index = all_fonts
-              .iter()
-              .position(|font| font.font_family == font_face.font_family);
-            if let Some(index) = has_font_index {
-              all_fonts[index] = font_face.clone();
-            } else {
-              all_fonts.push(font_face.clone());
-            }
-          }
         });
+        // 所有描述符读取完成后再记录字体
+        if !font_face.font_family.is_empty() && !font_face.sources.is_empty() {
+          let mut all_fonts = self.all_fonts.borrow_mut();
+          let has_font_index = all_fonts
+            .iter()
+            .position(|font| font.is_same_face(&font_face));
+          if let Some(index) = has_font_index {
+            all_fonts[index] = font_face;
+          } else {
+            all_fonts.push(font_face);
+          }
+        }
       },
       _ => {}
     }
@@ -407,29 +1292,92 @@ pub struct StyleParser<'i> {
   pub all_style: Rc<RefCell<Vec<(u32, Selector, Vec<StyleDeclaration<'i>>)>>>,
   pub all_keyframes: Rc<RefCell<Vec<(u32, String, Vec<KeyFrameItem>)>>>,
   pub all_medias: Rc<RefCell<Vec<StyleMedia>>>,
+  pub all_containers: Rc<RefCell<Vec<StyleContainer>>>,
   pub all_fonts: Rc<RefCell<Vec<FontFaceItem>>>,
+  pub all_warnings: Rc<RefCell<Vec<StyleWarning>>>,
+  pub errors: Vec<StyleParseError>,
+  // 所有样式文件，包括 @import 引入的文件，下标即 source_index
+  pub sources: Rc<RefCell<Vec<StyleSource>>>,
+  import_resolver: Option<Rc<ImportResolver<'i>>>,
+  pub layers: Rc<RefCell<Vec<Vec<String>>>>,
+  // 已解析的样式规则数量，用作下一个文件中规则顺序的起点
+  rule_count: u32,
+  platform: Platform,
 }
 
 impl<'i> StyleParser<'i> {
-  pub fn new(_: Platform) -> Self {
+  pub fn new(platform: Platform) -> Self {
     StyleParser {
       all_style: Rc::new(RefCell::new(vec![])),
       all_keyframes: Rc::new(RefCell::new(vec![])),
       all_medias: Rc::new(RefCell::new(vec![])),
+      all_containers: Rc::new(RefCell::new(vec![])),
       all_fonts: Rc::new(RefCell::new(vec![])),
+      all_warnings: Rc::new(RefCell::new(vec![])),
+      errors: vec![],
+      sources: Rc::new(RefCell::new(vec![])),
+      import_resolver: None,
+      layers: Rc::new(RefCell::new(vec![])),
+      rule_count: 0,
+      platform,
     }
   }
 
+  fn context(&self) -> StyleContext<'i> {
+    StyleContext {
+      all_style: Rc::clone(&self.all_style),
+      keyframes: Rc::clone(&self.all_keyframes),
+      all_fonts: Rc::clone(&self.all_fonts),
+      medias: Rc::clone(&self.all_medias),
+      containers: Rc::clone(&self.all_containers),
+      warnings: Rc::clone(&self.all_warnings),
+      sources: Rc::clone(&self.sources),
+      import_resolver: self.import_resolver.clone(),
+      layers: Rc::clone(&self.layers),
+      platform: self.platform.clone(),
+    }
+  }
+
+  // 设置 @import 的解析方式，未设置时忽略 @import
+  pub fn set_import_resolver(&mut self, import_resolver: Rc<ImportResolver<'i>>) {
+    self.import_resolver = Some(import_resolver);
+  }
+
+  // 解析样式，开启容错模式，无法解析的规则会被跳过并记录到 errors 中，而不是直接 panic
   pub fn parse(&mut self, css: &'i str) {
-    let mut stylesheet = StyleSheet::parse(css, ParserOptions::default()).expect("解析样式失败");
-    let mut style_visitor = StyleVisitor::new(
-      Rc::clone(&self.all_style),
-      Rc::clone(&self.all_keyframes),
-      Rc::clone(&self.all_fonts),
-      Rc::clone(&self.all_medias),
-      0,
-    );
-    stylesheet.visit(&mut style_visitor).unwrap();
+    self.parse_file("", css);
+  }
+
+  // 解析单个样式文件，每个文件拥有独立的 source_index，多次调用时按调用顺序合并样式
+  pub fn parse_file(&mut self, filename: &str, css: &'i str) {
+    let source_index = {
+      let mut sources = self.sources.borrow_mut();
+      sources.push(StyleSource {
+        filename: filename.to_string(),
+        importer: None,
+      });
+      sources.len() as u32 - 1
+    };
+    let warnings = Arc::new(RwLock::new(vec![]));
+    let result = StyleSheet::parse(css, parser_options(filename, source_index, &warnings));
+    if let Ok(warnings) = warnings.read() {
+      self.errors.extend(
+        warnings
+          .iter()
+          .map(|error| StyleParseError::from_error(error, source_index)),
+      );
+    }
+    match result {
+      Ok(mut stylesheet) => {
+        let mut style_visitor = StyleVisitor::new(self.context(), 0, self.rule_count, vec![filename.to_string()]);
+        stylesheet.visit(&mut style_visitor).unwrap();
+        self.rule_count = style_visitor.rule_order;
+        self.errors.append(&mut style_visitor.errors);
+      }
+      Err(error) => {
+        self.errors.push(StyleParseError::from_error(&error, source_index));
+      }
+    }
   }
 
   pub fn calc(&self) -> StyleData {
@@ -441,7 +1389,7 @@ impl<'i> StyleParser<'i> {
     let mut binding = self.calc_style_record();
     binding
       .iter_mut()
-      .for_each(|(media_index, selector, style_value)| {
+      .for_each(|(media_index, selector, style_value, locations, important_locations)| {
         // 辅助函数，用于处理属性转换，减少代码重复
         let convert_properties = |props: &Vec<Property<'i>>| -> Vec<(String, Property<'i>)> {
           props
@@ -466,32 +1414,59 @@ impl<'i> StyleParser<'i> {
         let properties = convert_properties(&style_value.declaration.declarations);
         let important_properties = convert_properties(&style_value.declaration.important_declarations);
         
-        final_all_style.push((media_index, selector.to_owned(), properties, important_properties));
+        final_all_style.push((media_index, selector.to_owned(), properties, important_properties, locations, important_locations, style_value));
       });
 
+    let layer_ranks = layer_ranks(&self.layers.borrow());
     // 进行样式解析优化，提前解析 ArkUI 的样式，减少运行时的计算
+    let mut warnings = self.all_warnings.borrow().clone();
     let final_all_style = final_all_style
       .iter_mut()
-      .map(|(media_index, selector, properties, important_properties)| {
+      .map(|(media_index, selector, properties, important_properties, locations, important_locations, style_value)| {
         let decls_and_vars = parse_style_properties(
           &properties
             .iter()
             .map(|(k, v)| (k.to_owned(), v.clone()))
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>(),
+          &self.platform,
         );
         let import_decls_and_vars = parse_style_properties(
           &important_properties
             .iter()
             .map(|(k, v)| (k.to_owned(), v.clone()))
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>(),
+          &self.platform,
         );
+        // 记录被丢弃的声明
+        for (dropped_list, locations) in [(&decls_and_vars.dropped, &**locations), (&import_decls_and_vars.dropped, &**important_locations)] {
+          for dropped in dropped_list.iter() {
+            warnings.push(StyleWarning::new(&selector.selector, dropped, &locations[dropped.index]));
+          }
+        }
+        let layer = style_value.layer.map_or(layer_ranks.len() as u32, |layer| layer_ranks[layer]);
         RuleItem {
           selector: selector.to_owned(),
           media: media_index.to_owned(),
           declarations: decls_and_vars.decls,
           important_declarections: import_decls_and_vars.decls,
           variables: decls_and_vars.vars,
-          has_env: decls_and_vars.has_env
+          has_env: decls_and_vars.has_env,
+          loc: style_value.loc,
+          specificity: style_value.specificity,
+          order: style_value.order,
+          layer,
+          important_layer: layer_ranks.len() as u32 - layer,
+          container: style_value.container,
+          declaration_locations: decls_and_vars
+            .decl_indexes
+            .iter()
+            .map(|index| locations[*index])
+            .collect(),
+          important_declaration_locations: import_decls_and_vars
+            .decl_indexes
+            .iter()
+            .map(|index| important_locations[*index])
+            .collect(),
         }
       }).collect::<Vec<RuleItem>>();
 
@@ -504,52 +1479,78 @@ impl<'i> StyleParser<'i> {
       })
       .collect::<IndexMap<(_, _), _>>();
 
+    warnings.sort_by_key(|warning| (warning.source_index, warning.line, warning.column));
+
     return StyleData {
       all_style: Rc::new(RefCell::new(final_all_style)),
       all_keyframes: Rc::new(RefCell::new(final_all_keyframes)),
       all_medias: self.all_medias.clone(),
+      all_containers: self.all_containers.clone(),
       all_fonts: self.all_fonts.clone(),
+      warnings,
     };
   }
 
-  // 合并相同类型的 style，比如 .a { color: red } .a { color: blue } => .a { color: blue }，并且 !important 的优先级高于普通的
+  // 合并同一条规则中相同的选择器，如 .a, .a {} 以及 :is() 展开后相同的选择器，并且 !important 的优先级高于普通的
+  // 不同规则中的相同选择器不合并，由运行时按 (specificity, order) 层叠，否则 .b {} .c {} .b {} 中第一个 .b 会排到 .c 之后
+  // 同时返回每条普通属性、important 属性所在规则的位置，用于输出警告
   fn calc_style_record(
     &self,
     //style_record: &mut Vec<(u32, T, Vec<StyleDeclaration<'i>>)>,
-  ) -> Vec<(u32, Selector, StyleDeclaration<'i>)> {
+  ) -> Vec<StyleRecord<'i>> {
     let mut style_record = self.all_style.borrow_mut();
     // 创建一个新的向量 final_style_record，用于存储最终的样式记录
     let mut final_style_record = vec![];
     // 对输入的 style_record 中的每个元素进行迭代
     for (media_index, id, declarations) in style_record.iter_mut() {
-      // 对每个 declarations 中的 StyleDeclaration 进行按 specificity 排序
-      declarations.sort_by(|a: &StyleDeclaration<'_>, b| a.specificity.cmp(&b.specificity));
+      // 对每个 declarations 中的 StyleDeclaration 进行按 specificity、规则顺序排序
+      declarations.sort_by_key(|declaration| (declaration.specificity, declaration.order));
       let mut final_properties: Vec<Property<'i>> = Vec::new();
+      let mut locations: Vec<Location> = Vec::new();
       for declaration in declarations.iter() {
+        let loc = declaration.loc;
         let declaration = &declaration.declaration;
         let declarations = &declaration.declarations;
         for declaration in declarations.iter() {
           final_properties.push(declaration.clone());
+          locations.push(loc);
         }
       }
       let mut important_properties: Vec<Property<'i>> = Vec::new();
+      let mut important_locations: Vec<Location> = Vec::new();
       for declaration in declarations.iter() {
+        let loc = declaration.loc;
         let declaration = &declaration.declaration;
         let important_declarations = &declaration.important_declarations;
         for declaration in important_declarations.iter() {
           important_properties.push(declaration.clone());
+          important_locations.push(loc);
         }
       }
       final_style_record.push((
         media_index.to_owned(),
         (*id).clone(),
         StyleDeclaration {
-          specificity: 0,
+          // 同一条规则中 :is()、:where() 展开后相同的选择器优先级可能不同，取最高的优先级
+          specificity: declarations.iter().map(|declaration| declaration.specificity).max().unwrap_or(0),
+          order: declarations.iter().map(|declaration| declaration.order).max().unwrap_or(0),
+          // 不同层中的相同选择器不会合并
+          layer: declarations.first().and_then(|declaration| declaration.layer),
+          // 不同 @container 中的相同选择器不会合并
+          container: declarations.first().map_or(0, |declaration| declaration.container),
           declaration: DeclarationBlock {
             declarations: final_properties,
             important_declarations: important_properties,
           },
+          // 合并后的声明使用规则所在的位置
+          loc: declarations
+            .iter()
+            .map(|declaration| declaration.loc)
+            .min_by_key(|loc| (loc.source_index, loc.line, loc.column))
+            .unwrap_or(Location { source_index: 0, line: 0, column: 1 }),
         },
+        locations,
+        important_locations,
       ));
     }
     final_style_record
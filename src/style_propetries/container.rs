@@ -0,0 +1,107 @@
+use lightningcss::{
+  printer::PrinterOptions,
+  properties::{
+    contain::{ContainerNameList, ContainerType},
+    Property,
+  },
+  traits::ToCss,
+};
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::ast::{ArrayLit, Expr, ExprOrSpread},
+};
+
+use crate::{generate_expr_enum, generate_expr_lit_str_raw, generate_invalid_expr, style_propetries::style_property_enum};
+
+use super::{style_property_type::CSSPropertyType, traits::ToExpr, unit::PropertyTuple};
+
+// container-type、container-name 以及 container 复合属性
+#[derive(Debug, Clone)]
+pub struct Container {
+  pub container_type: Option<EnumValue>,
+  // 容器名称列表，none 为空数组
+  pub container_name: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumValue {
+  Normal,
+  Size,
+  InlineSize,
+}
+
+impl From<&ContainerType> for EnumValue {
+  fn from(value: &ContainerType) -> Self {
+    match value {
+      ContainerType::Normal => EnumValue::Normal,
+      ContainerType::Size => EnumValue::Size,
+      ContainerType::InlineSize => EnumValue::InlineSize,
+    }
+  }
+}
+
+fn container_names(value: &ContainerNameList) -> Vec<String> {
+  match value {
+    ContainerNameList::None => vec![],
+    ContainerNameList::Names(names) => names
+      .iter()
+      .filter_map(|name| name.to_css_string(PrinterOptions::default()).ok())
+      .collect(),
+  }
+}
+
+impl From<(String, &Property<'_>)> for Container {
+  fn from(value: (String, &Property<'_>)) -> Self {
+    let (container_type, container_name) = match value.1 {
+      Property::ContainerType(container_type) => (Some(EnumValue::from(container_type)), None),
+      Property::ContainerName(container_name) => (None, Some(container_names(container_name))),
+      Property::Container(container) => (
+        Some(EnumValue::from(&container.container_type)),
+        Some(container_names(&container.name)),
+      ),
+      _ => (None, None),
+    };
+    Container {
+      container_type,
+      container_name,
+    }
+  }
+}
+
+impl ToExpr for Container {
+  fn to_expr(&self) -> PropertyTuple {
+    let mut props = vec![];
+    if let Some(container_type) = &self.container_type {
+      props.push((CSSPropertyType::ContainerType, match container_type {
+        EnumValue::Normal => generate_expr_enum!(style_property_enum::ContainerType::Normal),
+        EnumValue::Size => generate_expr_enum!(style_property_enum::ContainerType::Size),
+        EnumValue::InlineSize => generate_expr_enum!(style_property_enum::ContainerType::InlineSize),
+      }));
+    }
+    if let Some(container_name) = &self.container_name {
+      props.push((
+        CSSPropertyType::ContainerName,
+        Expr::Array(ArrayLit {
+          span: DUMMY_SP,
+          elems: container_name
+            .iter()
+            .map(|name| {
+              Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(generate_expr_lit_str_raw!(name.clone())),
+              })
+            })
+            .collect(),
+        }),
+      ));
+    }
+    match props.len() {
+      0 => PropertyTuple::One(CSSPropertyType::ContainerType, generate_invalid_expr!()),
+      1 => {
+        let (property, value) = props.remove(0);
+        PropertyTuple::One(property, value)
+      }
+      _ => PropertyTuple::Array(props),
+    }
+  }
+}
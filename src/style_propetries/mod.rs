@@ -14,6 +14,7 @@ pub mod border_width;
 pub mod box_orient;
 pub mod box_shadow;
 pub mod color;
+pub mod container;
 pub mod display;
 pub mod expr;
 pub mod variable;
@@ -40,6 +41,7 @@ pub mod opacity;
 pub mod overflow;
 pub mod position;
 pub mod size;
+pub mod style_container;
 pub mod style_media;
 pub mod style_property_enum;
 pub mod style_property_type;
@@ -0,0 +1,161 @@
+use lightningcss::media_query::{MediaFeatureName, MediaFeatureValue, Operator, QueryFeature};
+use lightningcss::printer::PrinterOptions;
+use lightningcss::rules::container::{ContainerCondition, ContainerName, ContainerSizeFeatureId};
+use lightningcss::rules::Location;
+use lightningcss::traits::ToCss;
+use swc_core::common::DUMMY_SP;
+use swc_core::ecma::ast::*;
+
+use crate::{generate_expr_lit_num, generate_expr_lit_str};
+
+use super::style_media::{
+  StyleMedia, StyleMediaCondType, StyleMediaCondition, StyleMediaFeature, StyleMediaFeatureID,
+  StyleMediaOpType,
+};
+
+// @container 查询，条件结构与 @media 相同，由运行时根据最近的满足 name 的容器尺寸求值
+#[derive(Debug, Clone)]
+pub struct StyleContainer {
+  pub container_id: u32,
+  // 查询的容器名称，未设置时为最近的容器
+  pub name: Option<String>,
+  // 外层 @container 的 id，0 表示没有外层
+  pub parent: u32,
+  pub conditions: Vec<StyleMediaCondition>,
+  // @container 规则所在的位置
+  pub loc: Location,
+}
+
+impl StyleContainer {
+  pub fn new(container_id: u32, name: Option<&ContainerName>, parent: u32, loc: Location) -> Self {
+    StyleContainer {
+      container_id,
+      name: name.and_then(|name| name.to_css_string(PrinterOptions::default()).ok()),
+      parent,
+      conditions: vec![],
+      loc,
+    }
+  }
+
+  // 条件中包含无法求值的部分时返回 false
+  pub fn parse(&mut self, condition: &ContainerCondition) -> bool {
+    match self.parse_condition(condition) {
+      Some(condition) => {
+        self.conditions.push(condition);
+        true
+      }
+      None => false,
+    }
+  }
+
+  // 用于复用 StyleMedia 的条件输出
+  pub fn as_media(&self) -> StyleMedia {
+    StyleMedia::new(self.container_id, self.conditions.clone(), self.loc)
+  }
+
+  pub fn to_expr(&self) -> Vec<PropOrSpread> {
+    let mut props = self.as_media().to_expr();
+    if let Some(name) = &self.name {
+      props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(Ident::new("name".into(), DUMMY_SP)),
+        value: Box::new(generate_expr_lit_str!(name.clone(), false)),
+      }))));
+    }
+    if self.parent > 0 {
+      props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(Ident::new("parent".into(), DUMMY_SP)),
+        value: Box::new(generate_expr_lit_num!(self.parent as f64)),
+      }))));
+    }
+    props
+  }
+
+  // 样式查询 style()、无法识别的特性以及无法转换的值在运行时无法求值，返回 None，包含它的 and、or、not 也无法求值
+  fn parse_condition(&mut self, condition: &ContainerCondition) -> Option<StyleMediaCondition> {
+    match condition {
+      ContainerCondition::Feature(feature) => match feature {
+        QueryFeature::Range {
+          name,
+          operator,
+          value,
+        } => self
+          .parse_feature(name, StyleMedia::parse_compare(operator), Some(value))
+          .map(StyleMediaCondition::Feature),
+        QueryFeature::Plain { name, value } => self
+          .parse_feature(name, StyleMediaOpType::Equal, Some(value))
+          .map(StyleMediaCondition::Feature),
+        QueryFeature::Boolean { name } => self
+          .parse_feature(name, StyleMediaOpType::Equal, None)
+          .map(StyleMediaCondition::Feature),
+        QueryFeature::Interval {
+          name,
+          start,
+          start_operator,
+          end,
+          end_operator,
+        } => Some(StyleMediaCondition::Operation {
+          operation: StyleMediaCondType::AND,
+          // 起始值位于特性名的左侧，如 100px < inline-size 即 inline-size > 100px
+          conditions: vec![
+            StyleMediaCondition::Feature(self.parse_feature(
+              name,
+              StyleMedia::parse_compare(start_operator).opposite(),
+              Some(start),
+            )?),
+            StyleMediaCondition::Feature(self.parse_feature(name, StyleMedia::parse_compare(end_operator), Some(end))?),
+          ],
+        }),
+      },
+      ContainerCondition::Not(not) => {
+        self
+          .parse_condition(not)
+          .map(|condition| StyleMediaCondition::Operation {
+            operation: StyleMediaCondType::NOT,
+            conditions: vec![condition],
+          })
+      }
+      ContainerCondition::Operation {
+        operator,
+        conditions,
+      } => Some(StyleMediaCondition::Operation {
+        operation: match operator {
+          Operator::And => StyleMediaCondType::AND,
+          Operator::Or => StyleMediaCondType::OR,
+        },
+        conditions: conditions
+          .iter()
+          .map(|condition| self.parse_condition(condition))
+          .collect::<Option<Vec<_>>>()?,
+      }),
+      ContainerCondition::Style(_) => None,
+    }
+  }
+
+  fn parse_feature(
+    &mut self,
+    feature_id: &MediaFeatureName<ContainerSizeFeatureId>,
+    feature_op: StyleMediaOpType,
+    feature_val: Option<&MediaFeatureValue>,
+  ) -> Option<StyleMediaFeature> {
+    let feature_id = match feature_id {
+      MediaFeatureName::Standard(feature_id) => match feature_id {
+        ContainerSizeFeatureId::Width => StyleMediaFeatureID::Width,
+        ContainerSizeFeatureId::Height => StyleMediaFeatureID::Height,
+        ContainerSizeFeatureId::InlineSize => StyleMediaFeatureID::InlineSize,
+        ContainerSizeFeatureId::BlockSize => StyleMediaFeatureID::BlockSize,
+        ContainerSizeFeatureId::AspectRatio => StyleMediaFeatureID::AspectRatio,
+        ContainerSizeFeatureId::Orientation => StyleMediaFeatureID::Orientation,
+      },
+      _ => return None,
+    };
+    let value = match feature_val {
+      Some(value) => Some(StyleMedia::parse_value(value)?),
+      None => None,
+    };
+    Some(StyleMediaFeature {
+      feature_id,
+      op: feature_op,
+      value,
+    })
+  }
+}
@@ -3,6 +3,7 @@ use std::borrow::Borrow;
 use lightningcss::media_query::*;
 use lightningcss::media_query::{self, MediaCondition, MediaQuery};
 use lightningcss::printer::PrinterOptions;
+use lightningcss::rules::Location;
 use lightningcss::traits::ToCss;
 use lightningcss::values::length::{Length, LengthValue};
 use swc_core::common::DUMMY_SP;
@@ -13,7 +14,7 @@ use crate::{
   generate_expr_by_length, generate_expr_enum, generate_expr_lit_num, generate_expr_lit_str,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StyleMediaFeatureID {
   Invalid = 0,
   Width = 1,
@@ -26,9 +27,14 @@ pub enum StyleMediaFeatureID {
   DeviceAspectRatio = 8,
   Resolution = 9,
   PrefersColorScheme = 10,
+  // 仅用于 @container
+  InlineSize = 11,
+  BlockSize = 12,
+  // 媒体类型，值为类型名称，如 screen、print
+  MediaType = 13,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MediaValueType {
   Length(Length),
   Float(f64),
@@ -36,7 +42,7 @@ pub enum MediaValueType {
   String(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StyleMediaOpType {
   /// `=`
   Equal = 0,
@@ -50,7 +56,20 @@ pub enum StyleMediaOpType {
   LessThanEqual = 4,
 }
 
-#[derive(Debug, Clone)]
+impl StyleMediaOpType {
+  // 交换比较的两侧，如 100px < width => width > 100px
+  pub fn opposite(&self) -> StyleMediaOpType {
+    match self {
+      StyleMediaOpType::Equal => StyleMediaOpType::Equal,
+      StyleMediaOpType::GreaterThan => StyleMediaOpType::LessThan,
+      StyleMediaOpType::GreaterThanEqual => StyleMediaOpType::LessThanEqual,
+      StyleMediaOpType::LessThan => StyleMediaOpType::GreaterThan,
+      StyleMediaOpType::LessThanEqual => StyleMediaOpType::GreaterThanEqual,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum StyleMediaCondType {
   /// 'none'
   None = 0,
@@ -62,14 +81,14 @@ pub enum StyleMediaCondType {
   OR = 3,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StyleMediaFeature {
   pub feature_id: StyleMediaFeatureID,
   pub op: StyleMediaOpType,
   pub value: Option<MediaValueType>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StyleMediaCondition {
   //not/and/or/feature
   Feature(StyleMediaFeature),
@@ -79,29 +98,118 @@ pub enum StyleMediaCondition {
   },
 }
 
+// 单个媒体查询的解析结果
+enum ParsedQuery {
+  // 恒成立，如 all、空的查询
+  Always,
+  // 包含无法求值的媒体特性
+  Unsupported,
+  Condition(StyleMediaCondition),
+}
+
 #[derive(Debug, Clone)]
 pub struct StyleMedia {
   pub media_id: u32,
   pub conditions: Vec<StyleMediaCondition>,
+  // @media 规则所在的位置
+  pub loc: Location,
 }
 
 impl StyleMedia {
-  pub fn new(media_id: u32, conditions: Vec<StyleMediaCondition>) -> Self {
+  pub fn new(media_id: u32, conditions: Vec<StyleMediaCondition>, loc: Location) -> Self {
     StyleMedia {
       media_id,
       conditions,
+      loc,
     }
   }
 
-  pub fn parse(&mut self, medias: &Vec<MediaQuery>) {
-    for media_query in medias {
-      if let Some(conditon) = &media_query.condition {
-        let ret_condition = self.parse_condition(conditon);
-        if ret_condition.is_some() {
-          self.conditions.push(ret_condition.unwrap());
-        }
+  // 以 and 合并外层与内层的条件，用于嵌套的 @media
+  pub fn combine(
+    outer: Vec<StyleMediaCondition>,
+    inner: Vec<StyleMediaCondition>,
+  ) -> Vec<StyleMediaCondition> {
+    if outer.is_empty() {
+      return inner;
+    }
+    if inner.is_empty() {
+      return outer;
+    }
+    vec![StyleMediaCondition::Operation {
+      operation: StyleMediaCondType::AND,
+      conditions: outer.into_iter().chain(inner).collect(),
+    }]
+  }
+
+  // 逗号分隔的多个查询以 or 条件表示，任意一个查询恒成立时整个列表恒成立，不输出条件
+  // 无法求值的查询视为不成立，从列表中移除，返回这些查询的下标，全部无法求值时由调用方丢弃整条规则
+  pub fn parse(&mut self, medias: &Vec<MediaQuery>) -> Vec<usize> {
+    let mut queries = vec![];
+    let mut always = false;
+    let mut unsupported = vec![];
+    for (index, media_query) in medias.iter().enumerate() {
+      match self.parse_query(media_query) {
+        ParsedQuery::Always => always = true,
+        ParsedQuery::Unsupported => unsupported.push(index),
+        ParsedQuery::Condition(query) => queries.push(query),
+      }
+    }
+    if always {
+      return unsupported;
+    }
+    if queries.len() > 1 {
+      self.conditions.push(StyleMediaCondition::Operation {
+        operation: StyleMediaCondType::OR,
+        conditions: queries,
+      });
+    } else {
+      self.conditions.extend(queries);
+    }
+    unsupported
+  }
+
+  // 解析单个查询，媒体类型与条件以 and 合并，not 作用于整个查询，only 不影响匹配结果
+  fn parse_query(&mut self, media_query: &MediaQuery) -> ParsedQuery {
+    let mut conditions = vec![];
+    let media_type = match &media_query.media_type {
+      MediaType::All => None,
+      MediaType::Print => Some("print".to_string()),
+      MediaType::Screen => Some("screen".to_string()),
+      MediaType::Custom(media_type) => Some(media_type.to_ascii_lowercase()),
+    };
+    if let Some(media_type) = media_type {
+      conditions.push(StyleMediaCondition::Feature(StyleMediaFeature {
+        feature_id: StyleMediaFeatureID::MediaType,
+        op: StyleMediaOpType::Equal,
+        value: Some(MediaValueType::String(media_type)),
+      }));
+    }
+    if let Some(condition) = &media_query.condition {
+      match self.parse_condition(condition) {
+        Some(condition) => conditions.push(condition),
+        None => return ParsedQuery::Unsupported,
       }
     }
+    let condition = match conditions.len() {
+      0 => None,
+      1 => conditions.pop(),
+      _ => Some(StyleMediaCondition::Operation {
+        operation: StyleMediaCondType::AND,
+        conditions,
+      }),
+    };
+    match (&media_query.qualifier, condition) {
+      // 空的 and 条件恒成立，not all 恒不成立
+      (Some(Qualifier::Not), condition) => ParsedQuery::Condition(StyleMediaCondition::Operation {
+        operation: StyleMediaCondType::NOT,
+        conditions: vec![condition.unwrap_or(StyleMediaCondition::Operation {
+          operation: StyleMediaCondType::AND,
+          conditions: vec![],
+        })],
+      }),
+      (_, Some(condition)) => ParsedQuery::Condition(condition),
+      (_, None) => ParsedQuery::Always,
+    }
   }
 
   pub fn to_expr(self) -> Vec<PropOrSpread> {
@@ -124,7 +232,7 @@ impl StyleMedia {
     ];
   }
 
-  fn value_to_expr(&self, value: Option<MediaValueType>) -> Option<ExprOrSpread> {
+  pub fn value_to_expr(&self, value: Option<MediaValueType>) -> Option<ExprOrSpread> {
     if let Some(tmp_val) = value {
       match tmp_val {
         MediaValueType::Length(val) => {
@@ -220,6 +328,7 @@ impl StyleMedia {
     }
   }
 
+  // 包含无法识别的媒体特性或无法转换的值时返回 None，包含它的 and、or、not 也无法求值
   fn parse_condition(&mut self, condition: &MediaCondition) -> Option<StyleMediaCondition> {
     match condition {
       MediaCondition::Feature(feature) => match feature {
@@ -228,7 +337,7 @@ impl StyleMedia {
           operator,
           value,
         } => {
-          let op = self.parse_compare(operator);
+          let op = Self::parse_compare(operator);
           let tmp_feature = self.parse_feature(name, op, Some(value));
           return match tmp_feature {
             Some(ret_feature) => Some(StyleMediaCondition::Feature(ret_feature)),
@@ -254,17 +363,15 @@ impl StyleMedia {
           end,
           end_operator,
         } => {
-          let start_op = self.parse_compare(start_operator);
-          let end_op = self.parse_compare(end_operator);
+          // 起始值位于特性名的左侧，如 400px < width 即 width > 400px
+          let start_op = Self::parse_compare(start_operator).opposite();
+          let end_op = Self::parse_compare(end_operator);
           return Some(StyleMediaCondition::Operation {
             operation: StyleMediaCondType::AND,
             conditions: vec![
-              self.parse_feature(name, start_op, Some(start)),
-              self.parse_feature(name, end_op, Some(end)),
-            ]
-            .into_iter()
-            .filter_map(|x| x.map(|y| StyleMediaCondition::Feature(y)))
-            .collect(),
+              StyleMediaCondition::Feature(self.parse_feature(name, start_op, Some(start))?),
+              StyleMediaCondition::Feature(self.parse_feature(name, end_op, Some(end))?),
+            ],
           });
         }
       },
@@ -291,13 +398,10 @@ impl StyleMedia {
           Operator::Or => StyleMediaCondType::OR,
         };
 
-        let mut ret_conditions = vec![];
-        conditions.iter().for_each(|condition| {
-          let ret_condition = self.parse_condition(condition);
-          if ret_condition.is_some() {
-            ret_conditions.push(ret_condition.unwrap());
-          }
-        });
+        let ret_conditions = conditions
+          .iter()
+          .map(|condition| self.parse_condition(condition))
+          .collect::<Option<Vec<_>>>()?;
         return Some(StyleMediaCondition::Operation {
           operation: op,
           conditions: ret_conditions,
@@ -313,11 +417,14 @@ impl StyleMedia {
     feature_val: Option<&MediaFeatureValue>,
   ) -> Option<StyleMediaFeature> {
     let id = self.convert_featureid(feature_id);
+    if id == StyleMediaFeatureID::Invalid {
+      return None;
+    }
     return match feature_val {
       Some(value) => Some(StyleMediaFeature {
         feature_id: id,
         op: feature_op,
-        value: self.parse_value(value),
+        value: Some(Self::parse_value(value)?),
       }),
       None => Some(StyleMediaFeature {
         feature_id: id,
@@ -327,7 +434,7 @@ impl StyleMedia {
     };
   }
 
-  fn parse_compare(&mut self, operator: &MediaFeatureComparison) -> StyleMediaOpType {
+  pub fn parse_compare(operator: &MediaFeatureComparison) -> StyleMediaOpType {
     match operator {
       MediaFeatureComparison::Equal => StyleMediaOpType::Equal,
       MediaFeatureComparison::GreaterThan => StyleMediaOpType::GreaterThan,
@@ -337,8 +444,10 @@ impl StyleMedia {
     }
   }
 
-  fn parse_value(&mut self, feature_val: &MediaFeatureValue) -> Option<MediaValueType> {
+  pub fn parse_value(feature_val: &MediaFeatureValue) -> Option<MediaValueType> {
     match feature_val {
+      // calc() 无法在运行时求值
+      MediaFeatureValue::Length(Length::Calc(_)) => None,
       MediaFeatureValue::Length(length_value) => {
         let length_str = length_value.to_css_string(PrinterOptions::default());
         return Some(MediaValueType::String(length_str.unwrap().clone()));
@@ -354,6 +354,14 @@ pub enum PointerEvents {
   None
 }
 
+#[repr(u32)]
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ContainerType {
+  Normal = 0,
+  Size,
+  InlineSize
+}
+
 
 #[repr(u32)]
 #[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
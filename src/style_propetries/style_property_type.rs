@@ -120,129 +120,155 @@ pub enum CSSPropertyType {
   BorderLeft = 115,
   TextUnderlineOffset = 116,
   BackdropFilter = 117,
+  ContainerType = 118,
+  ContainerName = 119,
   // ...
   All = 99999,       // used for transition-property
 }
 
-pub fn string_to_css_property_type(property: &str) -> CSSPropertyType {
-  match property {
-    "alignContent" => CSSPropertyType::AlignContent,
-    "justifyContent" => CSSPropertyType::JustifyContent,
-    "alignItems" => CSSPropertyType::AlignItems,
-    "alignSelf" => CSSPropertyType::AlignSelf,
-    "flexBasis" => CSSPropertyType::FlexBasis,
-    "flexDirection" => CSSPropertyType::FlexDirection,
-    "flexGrow" => CSSPropertyType::FlexGrow,
-    "flexShrink" => CSSPropertyType::FlexShrink,
-    "flexWrap" => CSSPropertyType::FlexWrap,
-    "aspectRatio" => CSSPropertyType::AspectRatio,
-    "display" => CSSPropertyType::Display,
-    "columnGap" => CSSPropertyType::ColumnGap,
-    "rowGap" => CSSPropertyType::RowGap,
-    "marginLeft" => CSSPropertyType::MarginLeft,
-    "marginRight" => CSSPropertyType::MarginRight,
-    "marginTop" => CSSPropertyType::MarginTop,
-    "marginBottom" => CSSPropertyType::MarginBottom,
-    "paddingLeft" => CSSPropertyType::PaddingLeft,
-    "paddingRight" => CSSPropertyType::PaddingRight,
-    "paddingTop" => CSSPropertyType::PaddingTop,
-    "paddingBottom" => CSSPropertyType::PaddingBottom,
-    "width" => CSSPropertyType::Width,
-    "minWidth" => CSSPropertyType::MinWidth,
-    "maxWidth" => CSSPropertyType::MaxWidth,
-    "height" => CSSPropertyType::Height,
-    "minHeight" => CSSPropertyType::MinHeight,
-    "maxHeight" => CSSPropertyType::MaxHeight,
-    "overflow" => CSSPropertyType::Overflow,
-    "fontSize" => CSSPropertyType::FontSize,
-    "fontStyle" => CSSPropertyType::FontStyle,
-    "fontFamily" => CSSPropertyType::FontFamily,
-    "fontWeight" => CSSPropertyType::FontWeight,
-    "lineHeight" => CSSPropertyType::LineHeight,
-    "letterSpacing" => CSSPropertyType::LetterSpacing,
-    "verticalAlign" => CSSPropertyType::VerticalAlign,
-    "textAlign" => CSSPropertyType::TextAlign,
-    "textDecoration" => CSSPropertyType::TextDecoration,
-    "textShadow" => CSSPropertyType::TextShadow,
-    "textOverflow" => CSSPropertyType::TextOverflow,
-    "textTransform" => CSSPropertyType::TextTransform,
-    "color" => CSSPropertyType::Color,
-    "backgroundColor" => CSSPropertyType::BackgroundColor,
-    "backgroundImage" => CSSPropertyType::BackgroundImage,
-    "backgroundPosition" => CSSPropertyType::BackgroundPosition,
-    "backgroundSize" => CSSPropertyType::BackgroundSize,
-    "backgroundRepeat" => CSSPropertyType::BackgroundRepeat,
-    "borderTopColor" => CSSPropertyType::BorderTopColor,
-    "borderRightColor" => CSSPropertyType::BorderRightColor,
-    "borderBottomColor" => CSSPropertyType::BorderBottomColor,
-    "borderLeftColor" => CSSPropertyType::BorderLeftColor,
-    "borderTopStyle" => CSSPropertyType::BorderTopStyle,
-    "borderRightStyle" => CSSPropertyType::BorderRightStyle,
-    "borderBottomStyle" => CSSPropertyType::BorderBottomStyle,
-    "borderLeftStyle" => CSSPropertyType::BorderLeftStyle,
-    "borderTopWidth" => CSSPropertyType::BorderTopWidth,
-    "borderRightWidth" => CSSPropertyType::BorderRightWidth,
-    "borderBottomWidth" => CSSPropertyType::BorderBottomWidth,
-    "borderLeftWidth" => CSSPropertyType::BorderLeftWidth,
-    "borderTopLeftRadius" => CSSPropertyType::BorderTopLeftRadius,
-    "borderTopRightRadius" => CSSPropertyType::BorderTopRightRadius,
-    "borderBottomLeftRadius" => CSSPropertyType::BorderBottomLeftRadius,
-    "borderBottomRightRadius" => CSSPropertyType::BorderBottomRightRadius,
-    "boxShadow" => CSSPropertyType::BoxShadow,
-    "zIndex" => CSSPropertyType::ZIndex,
-    "position" => CSSPropertyType::Position,
-    "top" => CSSPropertyType::Top,
-    "right" => CSSPropertyType::Right,
-    "bottom" => CSSPropertyType::Bottom,
-    "left" => CSSPropertyType::Left,
-    "visibility" => CSSPropertyType::Visibility,
-    "opacity" => CSSPropertyType::Opacity,
-    "transform" => CSSPropertyType::Transform,
-    "transformOrigin" => CSSPropertyType::TransformOrigin,
-    "animation" => CSSPropertyType::Animation,
-    "animationKeyFrames" => CSSPropertyType::AnimationKeyFrames,
-    "animationDuration" => CSSPropertyType::AnimationDuration,
-    "animationTimingFunction" => CSSPropertyType::AnimationTimingFunction,
-    "animationDelay" => CSSPropertyType::AnimationDelay,
-    "animationIterationCount" => CSSPropertyType::AnimationIterationCount,
-    "content" => CSSPropertyType::Content,
-    "wordBreak" => CSSPropertyType::WordBreak,
-    "PointerEvents" => CSSPropertyType::PointerEvents,
-    "webkitLineClamp" => CSSPropertyType::WebkitLineClamp,
-    "animationFillMode" => CSSPropertyType::AnimationFillMode,
-    "backgroundPositionX" => CSSPropertyType::BackgroundPositionX,
-    "backgroundPositionY" => CSSPropertyType::BackgroundPositionY,
-    "transition" => CSSPropertyType::Transition,
-    "transitionProperty" => CSSPropertyType::TransitionProperty,
-    "transitionDuration" => CSSPropertyType::TransitionDuration,
-    "transitionTimingFunction" => CSSPropertyType::TransitionTimingFunction,
-    "transitionDelay" => CSSPropertyType::TransitionDelay,
-    "whiteSpace" => CSSPropertyType::WhiteSpace,
-    "textDecorationLine" => CSSPropertyType::TextDecorationLine,
-    "textDecorationThickness" => CSSPropertyType::TextDecorationThickness,
-    "textDecorationStyle" => CSSPropertyType::TextDecorationStyle,
-    "textDecorationColor" => CSSPropertyType::TextDecorationColor,
-    "textUnderlineOffset" => CSSPropertyType::TextUnderlineOffset,
-    "animationName" => CSSPropertyType::AnimationName,
-    "borderWidth" => CSSPropertyType::BorderWidth,
-    "borderColor" => CSSPropertyType::BorderColor,
-    "margin" => CSSPropertyType::Margin,
-    "padding" => CSSPropertyType::Padding,
-    "borderRadius" => CSSPropertyType::BorderRadius,
-    "boxOrient" => CSSPropertyType::BoxOrient,
-    // S 复合属性，仅用作css变量的解析 ====
-    "background" => CSSPropertyType::Background,
-    "flex" => CSSPropertyType::Flex,
-    "border" => CSSPropertyType::Border,
-    "gap" => CSSPropertyType::Gap,
-    "borderStyle" => CSSPropertyType::BorderStyle,
-    "filter" => CSSPropertyType::Filter,
-    "borderTop" => CSSPropertyType::BorderTop,
-    "borderRight" => CSSPropertyType::BorderRight,
-    "borderBottom" => CSSPropertyType::BorderBottom,
-    "borderLeft" => CSSPropertyType::BorderLeft,
-    "backdropFilter" => CSSPropertyType::BackdropFilter,
-    // E 复合属性，仅用作css变量的解析 ====
-    _ => CSSPropertyType::Invalid,
-  }
+// 生成属性名与属性枚举之间的相互转换，两个方向共用同一份列表
+macro_rules! css_property_names {
+  ($(($property_type:ident, $name:literal)),* $(,)?) => {
+    pub fn string_to_css_property_type(property: &str) -> CSSPropertyType {
+      match property {
+        $($name => CSSPropertyType::$property_type,)*
+        _ => CSSPropertyType::Invalid,
+      }
+    }
+
+    // string_to_css_property_type 支持的所有属性
+    pub const CSS_PROPERTY_NAMES: &[(CSSPropertyType, &str)] = &[
+      $((CSSPropertyType::$property_type, $name),)*
+    ];
+  };
+}
+
+css_property_names! {
+  (AlignContent, "alignContent"),
+  (JustifyContent, "justifyContent"),
+  (AlignItems, "alignItems"),
+  (AlignSelf, "alignSelf"),
+  (FlexBasis, "flexBasis"),
+  (FlexDirection, "flexDirection"),
+  (FlexGrow, "flexGrow"),
+  (FlexShrink, "flexShrink"),
+  (FlexWrap, "flexWrap"),
+  (AspectRatio, "aspectRatio"),
+  (Display, "display"),
+  (ColumnGap, "columnGap"),
+  (RowGap, "rowGap"),
+  (MarginLeft, "marginLeft"),
+  (MarginRight, "marginRight"),
+  (MarginTop, "marginTop"),
+  (MarginBottom, "marginBottom"),
+  (PaddingLeft, "paddingLeft"),
+  (PaddingRight, "paddingRight"),
+  (PaddingTop, "paddingTop"),
+  (PaddingBottom, "paddingBottom"),
+  (Width, "width"),
+  (MinWidth, "minWidth"),
+  (MaxWidth, "maxWidth"),
+  (Height, "height"),
+  (MinHeight, "minHeight"),
+  (MaxHeight, "maxHeight"),
+  (Overflow, "overflow"),
+  (FontSize, "fontSize"),
+  (FontStyle, "fontStyle"),
+  (FontFamily, "fontFamily"),
+  (FontWeight, "fontWeight"),
+  (LineHeight, "lineHeight"),
+  (LetterSpacing, "letterSpacing"),
+  (VerticalAlign, "verticalAlign"),
+  (TextAlign, "textAlign"),
+  (TextDecoration, "textDecoration"),
+  (TextShadow, "textShadow"),
+  (TextOverflow, "textOverflow"),
+  (TextTransform, "textTransform"),
+  (Color, "color"),
+  (BackgroundColor, "backgroundColor"),
+  (BackgroundImage, "backgroundImage"),
+  (BackgroundPosition, "backgroundPosition"),
+  (BackgroundSize, "backgroundSize"),
+  (BackgroundRepeat, "backgroundRepeat"),
+  (BorderTopColor, "borderTopColor"),
+  (BorderRightColor, "borderRightColor"),
+  (BorderBottomColor, "borderBottomColor"),
+  (BorderLeftColor, "borderLeftColor"),
+  (BorderTopStyle, "borderTopStyle"),
+  (BorderRightStyle, "borderRightStyle"),
+  (BorderBottomStyle, "borderBottomStyle"),
+  (BorderLeftStyle, "borderLeftStyle"),
+  (BorderTopWidth, "borderTopWidth"),
+  (BorderRightWidth, "borderRightWidth"),
+  (BorderBottomWidth, "borderBottomWidth"),
+  (BorderLeftWidth, "borderLeftWidth"),
+  (BorderTopLeftRadius, "borderTopLeftRadius"),
+  (BorderTopRightRadius, "borderTopRightRadius"),
+  (BorderBottomLeftRadius, "borderBottomLeftRadius"),
+  (BorderBottomRightRadius, "borderBottomRightRadius"),
+  (BoxShadow, "boxShadow"),
+  (ZIndex, "zIndex"),
+  (Position, "position"),
+  (Top, "top"),
+  (Right, "right"),
+  (Bottom, "bottom"),
+  (Left, "left"),
+  (Visibility, "visibility"),
+  (Opacity, "opacity"),
+  (Transform, "transform"),
+  (TransformOrigin, "transformOrigin"),
+  (Animation, "animation"),
+  (AnimationKeyFrames, "animationKeyFrames"),
+  (AnimationDuration, "animationDuration"),
+  (AnimationTimingFunction, "animationTimingFunction"),
+  (AnimationDelay, "animationDelay"),
+  (AnimationIterationCount, "animationIterationCount"),
+  (Content, "content"),
+  (WordBreak, "wordBreak"),
+  (PointerEvents, "PointerEvents"),
+  (WebkitLineClamp, "webkitLineClamp"),
+  (AnimationFillMode, "animationFillMode"),
+  (BackgroundPositionX, "backgroundPositionX"),
+  (BackgroundPositionY, "backgroundPositionY"),
+  (Transition, "transition"),
+  (TransitionProperty, "transitionProperty"),
+  (TransitionDuration, "transitionDuration"),
+  (TransitionTimingFunction, "transitionTimingFunction"),
+  (TransitionDelay, "transitionDelay"),
+  (WhiteSpace, "whiteSpace"),
+  (TextDecorationLine, "textDecorationLine"),
+  (TextDecorationThickness, "textDecorationThickness"),
+  (TextDecorationStyle, "textDecorationStyle"),
+  (TextDecorationColor, "textDecorationColor"),
+  (TextUnderlineOffset, "textUnderlineOffset"),
+  (AnimationName, "animationName"),
+  (BorderWidth, "borderWidth"),
+  (BorderColor, "borderColor"),
+  (Margin, "margin"),
+  (Padding, "padding"),
+  (BorderRadius, "borderRadius"),
+  (BoxOrient, "boxOrient"),
+  (ContainerType, "containerType"),
+  (ContainerName, "containerName"),
+  // S 复合属性，仅用作css变量的解析 ====
+  (Background, "background"),
+  (Flex, "flex"),
+  (Border, "border"),
+  (Gap, "gap"),
+  (BorderStyle, "borderStyle"),
+  (Filter, "filter"),
+  (BorderTop, "borderTop"),
+  (BorderRight, "borderRight"),
+  (BorderBottom, "borderBottom"),
+  (BorderLeft, "borderLeft"),
+  (BackdropFilter, "backdropFilter"),
+  // E 复合属性，仅用作css变量的解析 ====
+}
+
+// 将属性 id 转换回属性名，用于调试输出
+pub fn css_property_type_to_string(property_id: u32) -> Option<&'static str> {
+  CSS_PROPERTY_NAMES
+    .iter()
+    .find(|(property_type, _)| *property_type as u32 == property_id)
+    .map(|(_, name)| *name)
 }
@@ -1711,10 +1711,11 @@ impl<'a> flatbuffers::Follow<'a> for DeclarationTuple<'a> {
 }
 
 impl<'a> DeclarationTuple<'a> {
-  pub const VT_PROPERTY_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PROPERTY_ID_V1: flatbuffers::VOffsetT = 4;
   pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 6;
   pub const VT_VALUE: flatbuffers::VOffsetT = 8;
   pub const VT_FLAG: flatbuffers::VOffsetT = 10;
+  pub const VT_PROPERTY_ID: flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1727,19 +1728,20 @@ impl<'a> DeclarationTuple<'a> {
   ) -> flatbuffers::WIPOffset<DeclarationTuple<'bldr>> {
     let mut builder = DeclarationTupleBuilder::new(_fbb);
     if let Some(x) = args.value { builder.add_value(x); }
+    builder.add_property_id(args.property_id);
     builder.add_flag(args.flag);
     builder.add_value_type(args.value_type);
-    builder.add_property_id(args.property_id);
+    builder.add_property_id_v1(args.property_id_v1);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn property_id(&self) -> u8 {
+  pub fn property_id_v1(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(DeclarationTuple::VT_PROPERTY_ID, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(DeclarationTuple::VT_PROPERTY_ID_V1, Some(0)).unwrap()}
   }
   #[inline]
   pub fn value_type(&self) -> Value {
@@ -1763,6 +1765,13 @@ impl<'a> DeclarationTuple<'a> {
     unsafe { self._tab.get::<u8>(DeclarationTuple::VT_FLAG, Some(0)).unwrap()}
   }
   #[inline]
+  pub fn property_id(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(DeclarationTuple::VT_PROPERTY_ID, Some(0)).unwrap()}
+  }
+  #[inline]
   #[allow(non_snake_case)]
   pub fn value_as_string(&self) -> Option<String<'a>> {
     if self.value_type() == Value::String {
@@ -1936,7 +1945,7 @@ impl flatbuffers::Verifiable for DeclarationTuple<'_> {
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u8>("property_id", Self::VT_PROPERTY_ID, false)?
+     .visit_field::<u8>("property_id_v1", Self::VT_PROPERTY_ID_V1, false)?
      .visit_union::<Value, _>("value_type", Self::VT_VALUE_TYPE, "value", Self::VT_VALUE, false, |key, v, pos| {
         match key {
           Value::String => v.verify_union_variant::<flatbuffers::ForwardsUOffset<String>>("Value::String", pos),
@@ -1954,24 +1963,27 @@ impl flatbuffers::Verifiable for DeclarationTuple<'_> {
         }
      })?
      .visit_field::<u8>("flag", Self::VT_FLAG, false)?
+     .visit_field::<u16>("property_id", Self::VT_PROPERTY_ID, false)?
      .finish();
     Ok(())
   }
 }
 pub struct DeclarationTupleArgs {
-    pub property_id: u8,
+    pub property_id_v1: u8,
     pub value_type: Value,
     pub value: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
     pub flag: u8,
+    pub property_id: u16,
 }
 impl<'a> Default for DeclarationTupleArgs {
   #[inline]
   fn default() -> Self {
     DeclarationTupleArgs {
-      property_id: 0,
+      property_id_v1: 0,
       value_type: Value::NONE,
       value: None,
       flag: 0,
+      property_id: 0,
     }
   }
 }
@@ -1982,8 +1994,8 @@ pub struct DeclarationTupleBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
 }
 impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DeclarationTupleBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_property_id(&mut self, property_id: u8) {
-    self.fbb_.push_slot::<u8>(DeclarationTuple::VT_PROPERTY_ID, property_id, 0);
+  pub fn add_property_id_v1(&mut self, property_id_v1: u8) {
+    self.fbb_.push_slot::<u8>(DeclarationTuple::VT_PROPERTY_ID_V1, property_id_v1, 0);
   }
   #[inline]
   pub fn add_value_type(&mut self, value_type: Value) {
@@ -1998,6 +2010,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DeclarationTupleBuilder<'a, 'b,
     self.fbb_.push_slot::<u8>(DeclarationTuple::VT_FLAG, flag, 0);
   }
   #[inline]
+  pub fn add_property_id(&mut self, property_id: u16) {
+    self.fbb_.push_slot::<u16>(DeclarationTuple::VT_PROPERTY_ID, property_id, 0);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DeclarationTupleBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     DeclarationTupleBuilder {
@@ -2015,7 +2031,7 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DeclarationTupleBuilder<'a, 'b,
 impl core::fmt::Debug for DeclarationTuple<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     let mut ds = f.debug_struct("DeclarationTuple");
-      ds.field("property_id", &self.property_id());
+      ds.field("property_id_v1", &self.property_id_v1());
       ds.field("value_type", &self.value_type());
       match self.value_type() {
         Value::String => {
@@ -2101,426 +2117,936 @@ impl core::fmt::Debug for DeclarationTuple<'_> {
         },
       };
       ds.field("flag", &self.flag());
+      ds.field("property_id", &self.property_id());
       ds.finish()
   }
 }
-pub enum SelectorOffset {}
+pub enum AttributeSelectorOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Selector<'a> {
+pub struct AttributeSelector<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Selector<'a> {
-  type Inner = Selector<'a>;
+impl<'a> flatbuffers::Follow<'a> for AttributeSelector<'a> {
+  type Inner = AttributeSelector<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Selector<'a> {
-  pub const VT_STRING_VALUE: flatbuffers::VOffsetT = 4;
-  pub const VT_INTEGER_VALUE: flatbuffers::VOffsetT = 6;
-  pub const VT_IS_STRING: flatbuffers::VOffsetT = 8;
+impl<'a> AttributeSelector<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_OPERATOR: flatbuffers::VOffsetT = 6;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 8;
+  pub const VT_CASE_INSENSITIVE: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Selector { _tab: table }
+    AttributeSelector { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SelectorArgs<'args>
-  ) -> flatbuffers::WIPOffset<Selector<'bldr>> {
-    let mut builder = SelectorBuilder::new(_fbb);
-    if let Some(x) = args.string_value { builder.add_string_value(x); }
-    builder.add_is_string(args.is_string);
-    builder.add_integer_value(args.integer_value);
+    args: &'args AttributeSelectorArgs<'args>
+  ) -> flatbuffers::WIPOffset<AttributeSelector<'bldr>> {
+    let mut builder = AttributeSelectorBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_case_insensitive(args.case_insensitive);
+    builder.add_operator(args.operator);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn string_value(&self) -> Option<&'a str> {
+  pub fn name(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Selector::VT_STRING_VALUE, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AttributeSelector::VT_NAME, None)}
   }
   #[inline]
-  pub fn integer_value(&self) -> u8 {
+  pub fn operator(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(Selector::VT_INTEGER_VALUE, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(AttributeSelector::VT_OPERATOR, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn is_string(&self) -> bool {
+  pub fn value(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(Selector::VT_IS_STRING, Some(false)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AttributeSelector::VT_VALUE, None)}
+  }
+  #[inline]
+  pub fn case_insensitive(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(AttributeSelector::VT_CASE_INSENSITIVE, Some(false)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Selector<'_> {
+impl flatbuffers::Verifiable for AttributeSelector<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("string_value", Self::VT_STRING_VALUE, false)?
-     .visit_field::<u8>("integer_value", Self::VT_INTEGER_VALUE, false)?
-     .visit_field::<bool>("is_string", Self::VT_IS_STRING, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u8>("operator", Self::VT_OPERATOR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .visit_field::<bool>("case_insensitive", Self::VT_CASE_INSENSITIVE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SelectorArgs<'a> {
-    pub string_value: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub integer_value: u8,
-    pub is_string: bool,
+pub struct AttributeSelectorArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub operator: u8,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub case_insensitive: bool,
 }
-impl<'a> Default for SelectorArgs<'a> {
+impl<'a> Default for AttributeSelectorArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SelectorArgs {
-      string_value: None,
-      integer_value: 0,
-      is_string: false,
+    AttributeSelectorArgs {
+      name: None,
+      operator: 0,
+      value: None,
+      case_insensitive: false,
     }
   }
 }
 
-pub struct SelectorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct AttributeSelectorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SelectorBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AttributeSelectorBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_string_value(&mut self, string_value: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Selector::VT_STRING_VALUE, string_value);
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AttributeSelector::VT_NAME, name);
   }
   #[inline]
-  pub fn add_integer_value(&mut self, integer_value: u8) {
-    self.fbb_.push_slot::<u8>(Selector::VT_INTEGER_VALUE, integer_value, 0);
+  pub fn add_operator(&mut self, operator: u8) {
+    self.fbb_.push_slot::<u8>(AttributeSelector::VT_OPERATOR, operator, 0);
   }
   #[inline]
-  pub fn add_is_string(&mut self, is_string: bool) {
-    self.fbb_.push_slot::<bool>(Selector::VT_IS_STRING, is_string, false);
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AttributeSelector::VT_VALUE, value);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SelectorBuilder<'a, 'b, A> {
+  pub fn add_case_insensitive(&mut self, case_insensitive: bool) {
+    self.fbb_.push_slot::<bool>(AttributeSelector::VT_CASE_INSENSITIVE, case_insensitive, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AttributeSelectorBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SelectorBuilder {
+    AttributeSelectorBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Selector<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<AttributeSelector<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Selector<'_> {
+impl core::fmt::Debug for AttributeSelector<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Selector");
-      ds.field("string_value", &self.string_value());
-      ds.field("integer_value", &self.integer_value());
-      ds.field("is_string", &self.is_string());
+    let mut ds = f.debug_struct("AttributeSelector");
+      ds.field("name", &self.name());
+      ds.field("operator", &self.operator());
+      ds.field("value", &self.value());
+      ds.field("case_insensitive", &self.case_insensitive());
       ds.finish()
   }
 }
-pub enum PseudoKeyOffset {}
+pub enum PseudoSelectorOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct PseudoKey<'a> {
+pub struct PseudoSelector<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for PseudoKey<'a> {
-  type Inner = PseudoKey<'a>;
+impl<'a> flatbuffers::Follow<'a> for PseudoSelector<'a> {
+  type Inner = PseudoSelector<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> PseudoKey<'a> {
-  pub const VT_INTEGER_VALUE: flatbuffers::VOffsetT = 4;
-  pub const VT_BOOL_VALUE: flatbuffers::VOffsetT = 6;
-  pub const VT_IS_INT: flatbuffers::VOffsetT = 8;
+impl<'a> PseudoSelector<'a> {
+  pub const VT_PSEUDO: flatbuffers::VOffsetT = 4;
+  pub const VT_PSEUDO_KEY: flatbuffers::VOffsetT = 6;
+  pub const VT_PSEUDO_VAL: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    PseudoKey { _tab: table }
+    PseudoSelector { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args PseudoKeyArgs
-  ) -> flatbuffers::WIPOffset<PseudoKey<'bldr>> {
-    let mut builder = PseudoKeyBuilder::new(_fbb);
-    builder.add_is_int(args.is_int);
-    builder.add_bool_value(args.bool_value);
-    builder.add_integer_value(args.integer_value);
+    args: &'args PseudoSelectorArgs<'args>
+  ) -> flatbuffers::WIPOffset<PseudoSelector<'bldr>> {
+    let mut builder = PseudoSelectorBuilder::new(_fbb);
+    if let Some(x) = args.pseudo_val { builder.add_pseudo_val(x); }
+    if let Some(x) = args.pseudo_key { builder.add_pseudo_key(x); }
+    builder.add_pseudo(args.pseudo);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn integer_value(&self) -> i8 {
+  pub fn pseudo(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<i8>(PseudoKey::VT_INTEGER_VALUE, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(PseudoSelector::VT_PSEUDO, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn bool_value(&self) -> bool {
+  pub fn pseudo_key(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PseudoKey<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(PseudoKey::VT_BOOL_VALUE, Some(false)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PseudoKey>>>>(PseudoSelector::VT_PSEUDO_KEY, None)}
   }
   #[inline]
-  pub fn is_int(&self) -> bool {
+  pub fn pseudo_val(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(PseudoKey::VT_IS_INT, Some(false)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PseudoSelector::VT_PSEUDO_VAL, None)}
   }
 }
 
-impl flatbuffers::Verifiable for PseudoKey<'_> {
+impl flatbuffers::Verifiable for PseudoSelector<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<i8>("integer_value", Self::VT_INTEGER_VALUE, false)?
-     .visit_field::<bool>("bool_value", Self::VT_BOOL_VALUE, false)?
-     .visit_field::<bool>("is_int", Self::VT_IS_INT, false)?
+     .visit_field::<u8>("pseudo", Self::VT_PSEUDO, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PseudoKey>>>>("pseudo_key", Self::VT_PSEUDO_KEY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pseudo_val", Self::VT_PSEUDO_VAL, false)?
      .finish();
     Ok(())
   }
 }
-pub struct PseudoKeyArgs {
-    pub integer_value: i8,
-    pub bool_value: bool,
-    pub is_int: bool,
+pub struct PseudoSelectorArgs<'a> {
+    pub pseudo: u8,
+    pub pseudo_key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PseudoKey<'a>>>>>,
+    pub pseudo_val: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for PseudoKeyArgs {
+impl<'a> Default for PseudoSelectorArgs<'a> {
   #[inline]
   fn default() -> Self {
-    PseudoKeyArgs {
-      integer_value: 0,
-      bool_value: false,
-      is_int: false,
+    PseudoSelectorArgs {
+      pseudo: 0,
+      pseudo_key: None,
+      pseudo_val: None,
     }
   }
 }
 
-pub struct PseudoKeyBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct PseudoSelectorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PseudoKeyBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PseudoSelectorBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_integer_value(&mut self, integer_value: i8) {
-    self.fbb_.push_slot::<i8>(PseudoKey::VT_INTEGER_VALUE, integer_value, 0);
+  pub fn add_pseudo(&mut self, pseudo: u8) {
+    self.fbb_.push_slot::<u8>(PseudoSelector::VT_PSEUDO, pseudo, 0);
   }
   #[inline]
-  pub fn add_bool_value(&mut self, bool_value: bool) {
-    self.fbb_.push_slot::<bool>(PseudoKey::VT_BOOL_VALUE, bool_value, false);
+  pub fn add_pseudo_key(&mut self, pseudo_key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PseudoKey<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PseudoSelector::VT_PSEUDO_KEY, pseudo_key);
   }
   #[inline]
-  pub fn add_is_int(&mut self, is_int: bool) {
-    self.fbb_.push_slot::<bool>(PseudoKey::VT_IS_INT, is_int, false);
+  pub fn add_pseudo_val(&mut self, pseudo_val: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PseudoSelector::VT_PSEUDO_VAL, pseudo_val);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PseudoKeyBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PseudoSelectorBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    PseudoKeyBuilder {
+    PseudoSelectorBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<PseudoKey<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<PseudoSelector<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for PseudoKey<'_> {
+impl core::fmt::Debug for PseudoSelector<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("PseudoKey");
-      ds.field("integer_value", &self.integer_value());
-      ds.field("bool_value", &self.bool_value());
-      ds.field("is_int", &self.is_int());
+    let mut ds = f.debug_struct("PseudoSelector");
+      ds.field("pseudo", &self.pseudo());
+      ds.field("pseudo_key", &self.pseudo_key());
+      ds.field("pseudo_val", &self.pseudo_val());
       ds.finish()
   }
 }
-pub enum KeyValueStringOffset {}
+pub enum CompoundSelectorOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct KeyValueString<'a> {
+pub struct CompoundSelector<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for KeyValueString<'a> {
-  type Inner = KeyValueString<'a>;
+impl<'a> flatbuffers::Follow<'a> for CompoundSelector<'a> {
+  type Inner = CompoundSelector<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> KeyValueString<'a> {
-  pub const VT_KEY: flatbuffers::VOffsetT = 4;
-  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+impl<'a> CompoundSelector<'a> {
+  pub const VT_TAG: flatbuffers::VOffsetT = 4;
+  pub const VT_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_CLASSES: flatbuffers::VOffsetT = 8;
+  pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 10;
+  pub const VT_NEGATIONS: flatbuffers::VOffsetT = 12;
+  pub const VT_PSEUDOS: flatbuffers::VOffsetT = 14;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    KeyValueString { _tab: table }
+    CompoundSelector { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args KeyValueStringArgs<'args>
-  ) -> flatbuffers::WIPOffset<KeyValueString<'bldr>> {
-    let mut builder = KeyValueStringBuilder::new(_fbb);
-    if let Some(x) = args.value { builder.add_value(x); }
-    if let Some(x) = args.key { builder.add_key(x); }
+    args: &'args CompoundSelectorArgs<'args>
+  ) -> flatbuffers::WIPOffset<CompoundSelector<'bldr>> {
+    let mut builder = CompoundSelectorBuilder::new(_fbb);
+    if let Some(x) = args.pseudos { builder.add_pseudos(x); }
+    if let Some(x) = args.negations { builder.add_negations(x); }
+    if let Some(x) = args.attributes { builder.add_attributes(x); }
+    if let Some(x) = args.classes { builder.add_classes(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    if let Some(x) = args.tag { builder.add_tag(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn key(&self) -> Option<&'a str> {
+  pub fn tag(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(KeyValueString::VT_KEY, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CompoundSelector::VT_TAG, None)}
   }
   #[inline]
-  pub fn value(&self) -> Option<&'a str> {
+  pub fn id(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(KeyValueString::VT_VALUE, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CompoundSelector::VT_ID, None)}
+  }
+  #[inline]
+  pub fn classes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(CompoundSelector::VT_CLASSES, None)}
+  }
+  #[inline]
+  pub fn attributes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AttributeSelector<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AttributeSelector>>>>(CompoundSelector::VT_ATTRIBUTES, None)}
+  }
+  #[inline]
+  pub fn negations(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CompoundSelector<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CompoundSelector>>>>(CompoundSelector::VT_NEGATIONS, None)}
+  }
+  #[inline]
+  pub fn pseudos(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PseudoSelector<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PseudoSelector>>>>(CompoundSelector::VT_PSEUDOS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for KeyValueString<'_> {
+impl flatbuffers::Verifiable for CompoundSelector<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("tag", Self::VT_TAG, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("classes", Self::VT_CLASSES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AttributeSelector>>>>("attributes", Self::VT_ATTRIBUTES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CompoundSelector>>>>("negations", Self::VT_NEGATIONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PseudoSelector>>>>("pseudos", Self::VT_PSEUDOS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct KeyValueStringArgs<'a> {
-    pub key: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct CompoundSelectorArgs<'a> {
+    pub tag: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub classes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub attributes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AttributeSelector<'a>>>>>,
+    pub negations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CompoundSelector<'a>>>>>,
+    pub pseudos: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PseudoSelector<'a>>>>>,
 }
-impl<'a> Default for KeyValueStringArgs<'a> {
+impl<'a> Default for CompoundSelectorArgs<'a> {
   #[inline]
   fn default() -> Self {
-    KeyValueStringArgs {
-      key: None,
-      value: None,
+    CompoundSelectorArgs {
+      tag: None,
+      id: None,
+      classes: None,
+      attributes: None,
+      negations: None,
+      pseudos: None,
     }
   }
 }
 
-pub struct KeyValueStringBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CompoundSelectorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> KeyValueStringBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CompoundSelectorBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KeyValueString::VT_KEY, key);
+  pub fn add_tag(&mut self, tag: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundSelector::VT_TAG, tag);
   }
   #[inline]
-  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KeyValueString::VT_VALUE, value);
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundSelector::VT_ID, id);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> KeyValueStringBuilder<'a, 'b, A> {
+  pub fn add_classes(&mut self, classes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundSelector::VT_CLASSES, classes);
+  }
+  #[inline]
+  pub fn add_attributes(&mut self, attributes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<AttributeSelector<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundSelector::VT_ATTRIBUTES, attributes);
+  }
+  #[inline]
+  pub fn add_negations(&mut self, negations: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CompoundSelector<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundSelector::VT_NEGATIONS, negations);
+  }
+  #[inline]
+  pub fn add_pseudos(&mut self, pseudos: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PseudoSelector<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundSelector::VT_PSEUDOS, pseudos);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CompoundSelectorBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    KeyValueStringBuilder {
+    CompoundSelectorBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<KeyValueString<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<CompoundSelector<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for KeyValueString<'_> {
+impl core::fmt::Debug for CompoundSelector<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("KeyValueString");
-      ds.field("key", &self.key());
-      ds.field("value", &self.value());
+    let mut ds = f.debug_struct("CompoundSelector");
+      ds.field("tag", &self.tag());
+      ds.field("id", &self.id());
+      ds.field("classes", &self.classes());
+      ds.field("attributes", &self.attributes());
+      ds.field("negations", &self.negations());
+      ds.field("pseudos", &self.pseudos());
       ds.finish()
   }
 }
-pub enum StyleOffset {}
+pub enum SelectorOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Style<'a> {
+pub struct Selector<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Style<'a> {
-  type Inner = Style<'a>;
+impl<'a> flatbuffers::Follow<'a> for Selector<'a> {
+  type Inner = Selector<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Style<'a> {
-  pub const VT_DECLARATIONS: flatbuffers::VOffsetT = 4;
-  pub const VT_MEDIA: flatbuffers::VOffsetT = 6;
-  pub const VT_PSEUDO: flatbuffers::VOffsetT = 8;
-  pub const VT_PSEUDO_KEY: flatbuffers::VOffsetT = 10;
-  pub const VT_PSEUDO_VAL: flatbuffers::VOffsetT = 12;
-  pub const VT_SELECTOR: flatbuffers::VOffsetT = 14;
-  pub const VT_VARIABLES: flatbuffers::VOffsetT = 16;
+impl<'a> Selector<'a> {
+  pub const VT_STRING_VALUE: flatbuffers::VOffsetT = 4;
+  pub const VT_INTEGER_VALUE: flatbuffers::VOffsetT = 6;
+  pub const VT_IS_STRING: flatbuffers::VOffsetT = 8;
+  pub const VT_COMPOUND: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Style { _tab: table }
+    Selector { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StyleArgs<'args>
-  ) -> flatbuffers::WIPOffset<Style<'bldr>> {
-    let mut builder = StyleBuilder::new(_fbb);
-    if let Some(x) = args.variables { builder.add_variables(x); }
-    if let Some(x) = args.selector { builder.add_selector(x); }
-    if let Some(x) = args.pseudo_val { builder.add_pseudo_val(x); }
-    if let Some(x) = args.pseudo_key { builder.add_pseudo_key(x); }
-    if let Some(x) = args.declarations { builder.add_declarations(x); }
-    builder.add_pseudo(args.pseudo);
+    args: &'args SelectorArgs<'args>
+  ) -> flatbuffers::WIPOffset<Selector<'bldr>> {
+    let mut builder = SelectorBuilder::new(_fbb);
+    if let Some(x) = args.compound { builder.add_compound(x); }
+    if let Some(x) = args.string_value { builder.add_string_value(x); }
+    builder.add_is_string(args.is_string);
+    builder.add_integer_value(args.integer_value);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn string_value(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Selector::VT_STRING_VALUE, None)}
+  }
+  #[inline]
+  pub fn integer_value(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(Selector::VT_INTEGER_VALUE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn is_string(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Selector::VT_IS_STRING, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn compound(&self) -> Option<CompoundSelector<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<CompoundSelector>>(Selector::VT_COMPOUND, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Selector<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("string_value", Self::VT_STRING_VALUE, false)?
+     .visit_field::<u8>("integer_value", Self::VT_INTEGER_VALUE, false)?
+     .visit_field::<bool>("is_string", Self::VT_IS_STRING, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<CompoundSelector>>("compound", Self::VT_COMPOUND, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SelectorArgs<'a> {
+    pub string_value: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub integer_value: u8,
+    pub is_string: bool,
+    pub compound: Option<flatbuffers::WIPOffset<CompoundSelector<'a>>>,
+}
+impl<'a> Default for SelectorArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SelectorArgs {
+      string_value: None,
+      integer_value: 0,
+      is_string: false,
+      compound: None,
+    }
+  }
+}
+
+pub struct SelectorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SelectorBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_string_value(&mut self, string_value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Selector::VT_STRING_VALUE, string_value);
+  }
+  #[inline]
+  pub fn add_integer_value(&mut self, integer_value: u8) {
+    self.fbb_.push_slot::<u8>(Selector::VT_INTEGER_VALUE, integer_value, 0);
+  }
+  #[inline]
+  pub fn add_is_string(&mut self, is_string: bool) {
+    self.fbb_.push_slot::<bool>(Selector::VT_IS_STRING, is_string, false);
+  }
+  #[inline]
+  pub fn add_compound(&mut self, compound: flatbuffers::WIPOffset<CompoundSelector<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<CompoundSelector>>(Selector::VT_COMPOUND, compound);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SelectorBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SelectorBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Selector<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Selector<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Selector");
+      ds.field("string_value", &self.string_value());
+      ds.field("integer_value", &self.integer_value());
+      ds.field("is_string", &self.is_string());
+      ds.field("compound", &self.compound());
+      ds.finish()
+  }
+}
+pub enum PseudoKeyOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PseudoKey<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PseudoKey<'a> {
+  type Inner = PseudoKey<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PseudoKey<'a> {
+  pub const VT_INTEGER_VALUE_V1: flatbuffers::VOffsetT = 4;
+  pub const VT_BOOL_VALUE: flatbuffers::VOffsetT = 6;
+  pub const VT_IS_INT: flatbuffers::VOffsetT = 8;
+  pub const VT_INTEGER_VALUE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PseudoKey { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PseudoKeyArgs
+  ) -> flatbuffers::WIPOffset<PseudoKey<'bldr>> {
+    let mut builder = PseudoKeyBuilder::new(_fbb);
+    builder.add_integer_value(args.integer_value);
+    builder.add_is_int(args.is_int);
+    builder.add_bool_value(args.bool_value);
+    builder.add_integer_value_v1(args.integer_value_v1);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn integer_value_v1(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(PseudoKey::VT_INTEGER_VALUE_V1, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn bool_value(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PseudoKey::VT_BOOL_VALUE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn is_int(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PseudoKey::VT_IS_INT, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn integer_value(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(PseudoKey::VT_INTEGER_VALUE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PseudoKey<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i8>("integer_value_v1", Self::VT_INTEGER_VALUE_V1, false)?
+     .visit_field::<bool>("bool_value", Self::VT_BOOL_VALUE, false)?
+     .visit_field::<bool>("is_int", Self::VT_IS_INT, false)?
+     .visit_field::<i32>("integer_value", Self::VT_INTEGER_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PseudoKeyArgs {
+    pub integer_value_v1: i8,
+    pub bool_value: bool,
+    pub is_int: bool,
+    pub integer_value: i32,
+}
+impl<'a> Default for PseudoKeyArgs {
+  #[inline]
+  fn default() -> Self {
+    PseudoKeyArgs {
+      integer_value_v1: 0,
+      bool_value: false,
+      is_int: false,
+      integer_value: 0,
+    }
+  }
+}
+
+pub struct PseudoKeyBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PseudoKeyBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_integer_value_v1(&mut self, integer_value_v1: i8) {
+    self.fbb_.push_slot::<i8>(PseudoKey::VT_INTEGER_VALUE_V1, integer_value_v1, 0);
+  }
+  #[inline]
+  pub fn add_bool_value(&mut self, bool_value: bool) {
+    self.fbb_.push_slot::<bool>(PseudoKey::VT_BOOL_VALUE, bool_value, false);
+  }
+  #[inline]
+  pub fn add_is_int(&mut self, is_int: bool) {
+    self.fbb_.push_slot::<bool>(PseudoKey::VT_IS_INT, is_int, false);
+  }
+  #[inline]
+  pub fn add_integer_value(&mut self, integer_value: i32) {
+    self.fbb_.push_slot::<i32>(PseudoKey::VT_INTEGER_VALUE, integer_value, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PseudoKeyBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PseudoKeyBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PseudoKey<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PseudoKey<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PseudoKey");
+      ds.field("integer_value_v1", &self.integer_value_v1());
+      ds.field("bool_value", &self.bool_value());
+      ds.field("is_int", &self.is_int());
+      ds.field("integer_value", &self.integer_value());
+      ds.finish()
+  }
+}
+pub enum KeyValueStringOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct KeyValueString<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for KeyValueString<'a> {
+  type Inner = KeyValueString<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> KeyValueString<'a> {
+  pub const VT_KEY: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    KeyValueString { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args KeyValueStringArgs<'args>
+  ) -> flatbuffers::WIPOffset<KeyValueString<'bldr>> {
+    let mut builder = KeyValueStringBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.key { builder.add_key(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(KeyValueString::VT_KEY, None)}
+  }
+  #[inline]
+  pub fn value(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(KeyValueString::VT_VALUE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for KeyValueString<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct KeyValueStringArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for KeyValueStringArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    KeyValueStringArgs {
+      key: None,
+      value: None,
+    }
+  }
+}
+
+pub struct KeyValueStringBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> KeyValueStringBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KeyValueString::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KeyValueString::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> KeyValueStringBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    KeyValueStringBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<KeyValueString<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for KeyValueString<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("KeyValueString");
+      ds.field("key", &self.key());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum StyleOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Style<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Style<'a> {
+  type Inner = Style<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Style<'a> {
+  pub const VT_DECLARATIONS: flatbuffers::VOffsetT = 4;
+  pub const VT_MEDIA_V1: flatbuffers::VOffsetT = 6;
+  pub const VT_PSEUDO: flatbuffers::VOffsetT = 8;
+  pub const VT_PSEUDO_KEY: flatbuffers::VOffsetT = 10;
+  pub const VT_PSEUDO_VAL: flatbuffers::VOffsetT = 12;
+  pub const VT_SELECTOR: flatbuffers::VOffsetT = 14;
+  pub const VT_VARIABLES: flatbuffers::VOffsetT = 16;
+  pub const VT_HAS_ENV: flatbuffers::VOffsetT = 18;
+  pub const VT_MEDIA: flatbuffers::VOffsetT = 20;
+  pub const VT_SPECIFICITY: flatbuffers::VOffsetT = 22;
+  pub const VT_ORDER: flatbuffers::VOffsetT = 24;
+  pub const VT_LAYER: flatbuffers::VOffsetT = 26;
+  pub const VT_CONTAINER: flatbuffers::VOffsetT = 28;
+  pub const VT_IMPORTANT_LAYER: flatbuffers::VOffsetT = 30;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Style { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StyleArgs<'args>
+  ) -> flatbuffers::WIPOffset<Style<'bldr>> {
+    let mut builder = StyleBuilder::new(_fbb);
+    builder.add_important_layer(args.important_layer);
+    builder.add_container(args.container);
+    builder.add_layer(args.layer);
+    builder.add_order(args.order);
+    builder.add_specificity(args.specificity);
     builder.add_media(args.media);
+    if let Some(x) = args.variables { builder.add_variables(x); }
+    if let Some(x) = args.selector { builder.add_selector(x); }
+    if let Some(x) = args.pseudo_val { builder.add_pseudo_val(x); }
+    if let Some(x) = args.pseudo_key { builder.add_pseudo_key(x); }
+    if let Some(x) = args.declarations { builder.add_declarations(x); }
+    builder.add_has_env(args.has_env);
+    builder.add_pseudo(args.pseudo);
+    builder.add_media_v1(args.media_v1);
     builder.finish()
   }
 
@@ -2533,11 +3059,11 @@ impl<'a> Style<'a> {
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DeclarationTuple>>>>(Style::VT_DECLARATIONS, None)}
   }
   #[inline]
-  pub fn media(&self) -> u8 {
+  pub fn media_v1(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(Style::VT_MEDIA, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(Style::VT_MEDIA_V1, Some(0)).unwrap()}
   }
   #[inline]
   pub fn pseudo(&self) -> u8 {
@@ -2574,6 +3100,55 @@ impl<'a> Style<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValueString>>>>(Style::VT_VARIABLES, None)}
   }
+  #[inline]
+  pub fn has_env(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Style::VT_HAS_ENV, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn media(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Style::VT_MEDIA, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn specificity(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Style::VT_SPECIFICITY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn order(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Style::VT_ORDER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn layer(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Style::VT_LAYER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn container(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Style::VT_CONTAINER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn important_layer(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Style::VT_IMPORTANT_LAYER, Some(0)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Style<'_> {
@@ -2584,98 +3159,399 @@ impl flatbuffers::Verifiable for Style<'_> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<DeclarationTuple>>>>("declarations", Self::VT_DECLARATIONS, false)?
-     .visit_field::<u8>("media", Self::VT_MEDIA, false)?
+     .visit_field::<u8>("media_v1", Self::VT_MEDIA_V1, false)?
      .visit_field::<u8>("pseudo", Self::VT_PSEUDO, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PseudoKey>>>>("pseudo_key", Self::VT_PSEUDO_KEY, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pseudo_val", Self::VT_PSEUDO_VAL, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Selector>>>>("selector", Self::VT_SELECTOR, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValueString>>>>("variables", Self::VT_VARIABLES, false)?
+     .visit_field::<bool>("has_env", Self::VT_HAS_ENV, false)?
+     .visit_field::<u32>("media", Self::VT_MEDIA, false)?
+     .visit_field::<u32>("specificity", Self::VT_SPECIFICITY, false)?
+     .visit_field::<u32>("order", Self::VT_ORDER, false)?
+     .visit_field::<u32>("layer", Self::VT_LAYER, false)?
+     .visit_field::<u32>("container", Self::VT_CONTAINER, false)?
+     .visit_field::<u32>("important_layer", Self::VT_IMPORTANT_LAYER, false)?
      .finish();
     Ok(())
   }
 }
 pub struct StyleArgs<'a> {
     pub declarations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DeclarationTuple<'a>>>>>,
-    pub media: u8,
+    pub media_v1: u8,
     pub pseudo: u8,
     pub pseudo_key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PseudoKey<'a>>>>>,
     pub pseudo_val: Option<flatbuffers::WIPOffset<&'a str>>,
     pub selector: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Selector<'a>>>>>,
     pub variables: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValueString<'a>>>>>,
+    pub has_env: bool,
+    pub media: u32,
+    pub specificity: u32,
+    pub order: u32,
+    pub layer: u32,
+    pub container: u32,
+    pub important_layer: u32,
+}
+impl<'a> Default for StyleArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StyleArgs {
+      declarations: None,
+      media_v1: 0,
+      pseudo: 0,
+      pseudo_key: None,
+      pseudo_val: None,
+      selector: None,
+      variables: None,
+      has_env: false,
+      media: 0,
+      specificity: 0,
+      order: 0,
+      layer: 0,
+      container: 0,
+      important_layer: 0,
+    }
+  }
+}
+
+pub struct StyleBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StyleBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_declarations(&mut self, declarations: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<DeclarationTuple<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_DECLARATIONS, declarations);
+  }
+  #[inline]
+  pub fn add_media_v1(&mut self, media_v1: u8) {
+    self.fbb_.push_slot::<u8>(Style::VT_MEDIA_V1, media_v1, 0);
+  }
+  #[inline]
+  pub fn add_pseudo(&mut self, pseudo: u8) {
+    self.fbb_.push_slot::<u8>(Style::VT_PSEUDO, pseudo, 0);
+  }
+  #[inline]
+  pub fn add_pseudo_key(&mut self, pseudo_key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PseudoKey<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_PSEUDO_KEY, pseudo_key);
+  }
+  #[inline]
+  pub fn add_pseudo_val(&mut self, pseudo_val: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_PSEUDO_VAL, pseudo_val);
+  }
+  #[inline]
+  pub fn add_selector(&mut self, selector: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Selector<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_SELECTOR, selector);
+  }
+  #[inline]
+  pub fn add_variables(&mut self, variables: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<KeyValueString<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_VARIABLES, variables);
+  }
+  #[inline]
+  pub fn add_has_env(&mut self, has_env: bool) {
+    self.fbb_.push_slot::<bool>(Style::VT_HAS_ENV, has_env, false);
+  }
+  #[inline]
+  pub fn add_media(&mut self, media: u32) {
+    self.fbb_.push_slot::<u32>(Style::VT_MEDIA, media, 0);
+  }
+  #[inline]
+  pub fn add_specificity(&mut self, specificity: u32) {
+    self.fbb_.push_slot::<u32>(Style::VT_SPECIFICITY, specificity, 0);
+  }
+  #[inline]
+  pub fn add_order(&mut self, order: u32) {
+    self.fbb_.push_slot::<u32>(Style::VT_ORDER, order, 0);
+  }
+  #[inline]
+  pub fn add_layer(&mut self, layer: u32) {
+    self.fbb_.push_slot::<u32>(Style::VT_LAYER, layer, 0);
+  }
+  #[inline]
+  pub fn add_container(&mut self, container: u32) {
+    self.fbb_.push_slot::<u32>(Style::VT_CONTAINER, container, 0);
+  }
+  #[inline]
+  pub fn add_important_layer(&mut self, important_layer: u32) {
+    self.fbb_.push_slot::<u32>(Style::VT_IMPORTANT_LAYER, important_layer, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StyleBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StyleBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Style<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Style<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Style");
+      ds.field("declarations", &self.declarations());
+      ds.field("media_v1", &self.media_v1());
+      ds.field("pseudo", &self.pseudo());
+      ds.field("pseudo_key", &self.pseudo_key());
+      ds.field("pseudo_val", &self.pseudo_val());
+      ds.field("selector", &self.selector());
+      ds.field("variables", &self.variables());
+      ds.field("has_env", &self.has_env());
+      ds.field("media", &self.media());
+      ds.field("specificity", &self.specificity());
+      ds.field("order", &self.order());
+      ds.field("layer", &self.layer());
+      ds.field("container", &self.container());
+      ds.field("important_layer", &self.important_layer());
+      ds.finish()
+  }
+}
+pub enum FontSourceOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FontSource<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FontSource<'a> {
+  type Inner = FontSource<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FontSource<'a> {
+  pub const VT_URL: flatbuffers::VOffsetT = 4;
+  pub const VT_LOCAL: flatbuffers::VOffsetT = 6;
+  pub const VT_FORMAT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FontSource { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args FontSourceArgs<'args>
+  ) -> flatbuffers::WIPOffset<FontSource<'bldr>> {
+    let mut builder = FontSourceBuilder::new(_fbb);
+    if let Some(x) = args.format { builder.add_format(x); }
+    if let Some(x) = args.local { builder.add_local(x); }
+    if let Some(x) = args.url { builder.add_url(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FontSource::VT_URL, None)}
+  }
+  #[inline]
+  pub fn local(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FontSource::VT_LOCAL, None)}
+  }
+  #[inline]
+  pub fn format(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FontSource::VT_FORMAT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for FontSource<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("local", Self::VT_LOCAL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("format", Self::VT_FORMAT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FontSourceArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub local: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub format: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for FontSourceArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FontSourceArgs {
+      url: None,
+      local: None,
+      format: None,
+    }
+  }
+}
+
+pub struct FontSourceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FontSourceBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FontSource::VT_URL, url);
+  }
+  #[inline]
+  pub fn add_local(&mut self, local: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FontSource::VT_LOCAL, local);
+  }
+  #[inline]
+  pub fn add_format(&mut self, format: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FontSource::VT_FORMAT, format);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FontSourceBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FontSourceBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FontSource<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FontSource<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FontSource");
+      ds.field("url", &self.url());
+      ds.field("local", &self.local());
+      ds.field("format", &self.format());
+      ds.finish()
+  }
+}
+pub enum UnicodeRangeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct UnicodeRange<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for UnicodeRange<'a> {
+  type Inner = UnicodeRange<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> UnicodeRange<'a> {
+  pub const VT_START: flatbuffers::VOffsetT = 4;
+  pub const VT_END: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    UnicodeRange { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args UnicodeRangeArgs
+  ) -> flatbuffers::WIPOffset<UnicodeRange<'bldr>> {
+    let mut builder = UnicodeRangeBuilder::new(_fbb);
+    builder.add_end(args.end);
+    builder.add_start(args.start);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn start(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(UnicodeRange::VT_START, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn end(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(UnicodeRange::VT_END, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for UnicodeRange<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("start", Self::VT_START, false)?
+     .visit_field::<u32>("end", Self::VT_END, false)?
+     .finish();
+    Ok(())
+  }
 }
-impl<'a> Default for StyleArgs<'a> {
+pub struct UnicodeRangeArgs {
+    pub start: u32,
+    pub end: u32,
+}
+impl<'a> Default for UnicodeRangeArgs {
   #[inline]
   fn default() -> Self {
-    StyleArgs {
-      declarations: None,
-      media: 0,
-      pseudo: 0,
-      pseudo_key: None,
-      pseudo_val: None,
-      selector: None,
-      variables: None,
+    UnicodeRangeArgs {
+      start: 0,
+      end: 0,
     }
   }
 }
 
-pub struct StyleBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct UnicodeRangeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StyleBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_declarations(&mut self, declarations: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<DeclarationTuple<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_DECLARATIONS, declarations);
-  }
-  #[inline]
-  pub fn add_media(&mut self, media: u8) {
-    self.fbb_.push_slot::<u8>(Style::VT_MEDIA, media, 0);
-  }
-  #[inline]
-  pub fn add_pseudo(&mut self, pseudo: u8) {
-    self.fbb_.push_slot::<u8>(Style::VT_PSEUDO, pseudo, 0);
-  }
-  #[inline]
-  pub fn add_pseudo_key(&mut self, pseudo_key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<PseudoKey<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_PSEUDO_KEY, pseudo_key);
-  }
-  #[inline]
-  pub fn add_pseudo_val(&mut self, pseudo_val: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_PSEUDO_VAL, pseudo_val);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UnicodeRangeBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_selector(&mut self, selector: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Selector<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_SELECTOR, selector);
+  pub fn add_start(&mut self, start: u32) {
+    self.fbb_.push_slot::<u32>(UnicodeRange::VT_START, start, 0);
   }
   #[inline]
-  pub fn add_variables(&mut self, variables: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<KeyValueString<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Style::VT_VARIABLES, variables);
+  pub fn add_end(&mut self, end: u32) {
+    self.fbb_.push_slot::<u32>(UnicodeRange::VT_END, end, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StyleBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> UnicodeRangeBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StyleBuilder {
+    UnicodeRangeBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Style<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<UnicodeRange<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Style<'_> {
+impl core::fmt::Debug for UnicodeRange<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Style");
-      ds.field("declarations", &self.declarations());
-      ds.field("media", &self.media());
-      ds.field("pseudo", &self.pseudo());
-      ds.field("pseudo_key", &self.pseudo_key());
-      ds.field("pseudo_val", &self.pseudo_val());
-      ds.field("selector", &self.selector());
-      ds.field("variables", &self.variables());
+    let mut ds = f.debug_struct("UnicodeRange");
+      ds.field("start", &self.start());
+      ds.field("end", &self.end());
       ds.finish()
   }
 }
@@ -2697,6 +3573,13 @@ impl<'a> flatbuffers::Follow<'a> for Font<'a> {
 impl<'a> Font<'a> {
   pub const VT_FONT_FAMILY: flatbuffers::VOffsetT = 4;
   pub const VT_SRC: flatbuffers::VOffsetT = 6;
+  pub const VT_FONT_WEIGHT: flatbuffers::VOffsetT = 8;
+  pub const VT_SOURCES: flatbuffers::VOffsetT = 10;
+  pub const VT_FONT_STYLE: flatbuffers::VOffsetT = 12;
+  pub const VT_FONT_WEIGHT_MIN: flatbuffers::VOffsetT = 14;
+  pub const VT_FONT_WEIGHT_MAX: flatbuffers::VOffsetT = 16;
+  pub const VT_UNICODE_RANGE: flatbuffers::VOffsetT = 18;
+  pub const VT_FONT_DISPLAY: flatbuffers::VOffsetT = 20;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -2708,8 +3591,15 @@ impl<'a> Font<'a> {
     args: &'args FontArgs<'args>
   ) -> flatbuffers::WIPOffset<Font<'bldr>> {
     let mut builder = FontBuilder::new(_fbb);
+    if let Some(x) = args.font_display { builder.add_font_display(x); }
+    if let Some(x) = args.unicode_range { builder.add_unicode_range(x); }
+    if let Some(x) = args.sources { builder.add_sources(x); }
     if let Some(x) = args.src { builder.add_src(x); }
     if let Some(x) = args.font_family { builder.add_font_family(x); }
+    builder.add_font_weight_max(args.font_weight_max);
+    builder.add_font_weight_min(args.font_weight_min);
+    if let Some(x) = args.font_style { builder.add_font_style(x); }
+    if let Some(x) = args.font_weight { builder.add_font_weight(x); }
     builder.finish()
   }
 
@@ -2728,6 +3618,55 @@ impl<'a> Font<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Font::VT_SRC, None)}
   }
+  #[inline]
+  pub fn font_weight(&self) -> Option<u8> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(Font::VT_FONT_WEIGHT, None)}
+  }
+  #[inline]
+  pub fn sources(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FontSource<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FontSource>>>>(Font::VT_SOURCES, None)}
+  }
+  #[inline]
+  pub fn font_style(&self) -> Option<u8> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(Font::VT_FONT_STYLE, None)}
+  }
+  #[inline]
+  pub fn font_weight_min(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Font::VT_FONT_WEIGHT_MIN, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn font_weight_max(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Font::VT_FONT_WEIGHT_MAX, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn unicode_range(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<UnicodeRange<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<UnicodeRange>>>>(Font::VT_UNICODE_RANGE, None)}
+  }
+  #[inline]
+  pub fn font_display(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Font::VT_FONT_DISPLAY, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Font<'_> {
@@ -2739,6 +3678,13 @@ impl flatbuffers::Verifiable for Font<'_> {
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("font_family", Self::VT_FONT_FAMILY, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("src", Self::VT_SRC, false)?
+     .visit_field::<u8>("font_weight", Self::VT_FONT_WEIGHT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<FontSource>>>>("sources", Self::VT_SOURCES, false)?
+     .visit_field::<u8>("font_style", Self::VT_FONT_STYLE, false)?
+     .visit_field::<u16>("font_weight_min", Self::VT_FONT_WEIGHT_MIN, false)?
+     .visit_field::<u16>("font_weight_max", Self::VT_FONT_WEIGHT_MAX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<UnicodeRange>>>>("unicode_range", Self::VT_UNICODE_RANGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("font_display", Self::VT_FONT_DISPLAY, false)?
      .finish();
     Ok(())
   }
@@ -2746,6 +3692,13 @@ impl flatbuffers::Verifiable for Font<'_> {
 pub struct FontArgs<'a> {
     pub font_family: Option<flatbuffers::WIPOffset<&'a str>>,
     pub src: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub font_weight: Option<u8>,
+    pub sources: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<FontSource<'a>>>>>,
+    pub font_style: Option<u8>,
+    pub font_weight_min: u16,
+    pub font_weight_max: u16,
+    pub unicode_range: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<UnicodeRange<'a>>>>>,
+    pub font_display: Option<flatbuffers::WIPOffset<&'a str>>,
 }
 impl<'a> Default for FontArgs<'a> {
   #[inline]
@@ -2753,6 +3706,13 @@ impl<'a> Default for FontArgs<'a> {
     FontArgs {
       font_family: None,
       src: None,
+      font_weight: None,
+      sources: None,
+      font_style: None,
+      font_weight_min: 0,
+      font_weight_max: 0,
+      unicode_range: None,
+      font_display: None,
     }
   }
 }
@@ -2771,6 +3731,34 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FontBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Font::VT_SRC, src);
   }
   #[inline]
+  pub fn add_font_weight(&mut self, font_weight: u8) {
+    self.fbb_.push_slot_always::<u8>(Font::VT_FONT_WEIGHT, font_weight);
+  }
+  #[inline]
+  pub fn add_sources(&mut self, sources: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<FontSource<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Font::VT_SOURCES, sources);
+  }
+  #[inline]
+  pub fn add_font_style(&mut self, font_style: u8) {
+    self.fbb_.push_slot_always::<u8>(Font::VT_FONT_STYLE, font_style);
+  }
+  #[inline]
+  pub fn add_font_weight_min(&mut self, font_weight_min: u16) {
+    self.fbb_.push_slot::<u16>(Font::VT_FONT_WEIGHT_MIN, font_weight_min, 0);
+  }
+  #[inline]
+  pub fn add_font_weight_max(&mut self, font_weight_max: u16) {
+    self.fbb_.push_slot::<u16>(Font::VT_FONT_WEIGHT_MAX, font_weight_max, 0);
+  }
+  #[inline]
+  pub fn add_unicode_range(&mut self, unicode_range: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<UnicodeRange<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Font::VT_UNICODE_RANGE, unicode_range);
+  }
+  #[inline]
+  pub fn add_font_display(&mut self, font_display: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Font::VT_FONT_DISPLAY, font_display);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FontBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     FontBuilder {
@@ -2790,6 +3778,13 @@ impl core::fmt::Debug for Font<'_> {
     let mut ds = f.debug_struct("Font");
       ds.field("font_family", &self.font_family());
       ds.field("src", &self.src());
+      ds.field("font_weight", &self.font_weight());
+      ds.field("sources", &self.sources());
+      ds.field("font_style", &self.font_style());
+      ds.field("font_weight_min", &self.font_weight_min());
+      ds.field("font_weight_max", &self.font_weight_max());
+      ds.field("unicode_range", &self.unicode_range());
+      ds.field("font_display", &self.font_display());
       ds.finish()
   }
 }
@@ -2924,8 +3919,9 @@ impl<'a> flatbuffers::Follow<'a> for KeyframeAnimation<'a> {
 
 impl<'a> KeyframeAnimation<'a> {
   pub const VT_NAME: flatbuffers::VOffsetT = 4;
-  pub const VT_MEDIA: flatbuffers::VOffsetT = 6;
+  pub const VT_MEDIA_V1: flatbuffers::VOffsetT = 6;
   pub const VT_KEYFRAME_POINTS: flatbuffers::VOffsetT = 8;
+  pub const VT_MEDIA: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -2937,9 +3933,10 @@ impl<'a> KeyframeAnimation<'a> {
     args: &'args KeyframeAnimationArgs<'args>
   ) -> flatbuffers::WIPOffset<KeyframeAnimation<'bldr>> {
     let mut builder = KeyframeAnimationBuilder::new(_fbb);
+    builder.add_media(args.media);
     if let Some(x) = args.keyframe_points { builder.add_keyframe_points(x); }
     if let Some(x) = args.name { builder.add_name(x); }
-    builder.add_media(args.media);
+    builder.add_media_v1(args.media_v1);
     builder.finish()
   }
 
@@ -2952,11 +3949,11 @@ impl<'a> KeyframeAnimation<'a> {
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(KeyframeAnimation::VT_NAME, None)}
   }
   #[inline]
-  pub fn media(&self) -> u8 {
+  pub fn media_v1(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(KeyframeAnimation::VT_MEDIA, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(KeyframeAnimation::VT_MEDIA_V1, Some(0)).unwrap()}
   }
   #[inline]
   pub fn keyframe_points(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyframeAnimationPoint<'a>>>> {
@@ -2965,6 +3962,13 @@ impl<'a> KeyframeAnimation<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyframeAnimationPoint>>>>(KeyframeAnimation::VT_KEYFRAME_POINTS, None)}
   }
+  #[inline]
+  pub fn media(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(KeyframeAnimation::VT_MEDIA, Some(0)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for KeyframeAnimation<'_> {
@@ -2975,24 +3979,27 @@ impl flatbuffers::Verifiable for KeyframeAnimation<'_> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<u8>("media", Self::VT_MEDIA, false)?
+     .visit_field::<u8>("media_v1", Self::VT_MEDIA_V1, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyframeAnimationPoint>>>>("keyframe_points", Self::VT_KEYFRAME_POINTS, false)?
+     .visit_field::<u32>("media", Self::VT_MEDIA, false)?
      .finish();
     Ok(())
   }
 }
 pub struct KeyframeAnimationArgs<'a> {
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub media: u8,
+    pub media_v1: u8,
     pub keyframe_points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyframeAnimationPoint<'a>>>>>,
+    pub media: u32,
 }
 impl<'a> Default for KeyframeAnimationArgs<'a> {
   #[inline]
   fn default() -> Self {
     KeyframeAnimationArgs {
       name: None,
-      media: 0,
+      media_v1: 0,
       keyframe_points: None,
+      media: 0,
     }
   }
 }
@@ -3007,14 +4014,18 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> KeyframeAnimationBuilder<'a, 'b
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KeyframeAnimation::VT_NAME, name);
   }
   #[inline]
-  pub fn add_media(&mut self, media: u8) {
-    self.fbb_.push_slot::<u8>(KeyframeAnimation::VT_MEDIA, media, 0);
+  pub fn add_media_v1(&mut self, media_v1: u8) {
+    self.fbb_.push_slot::<u8>(KeyframeAnimation::VT_MEDIA_V1, media_v1, 0);
   }
   #[inline]
   pub fn add_keyframe_points(&mut self, keyframe_points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<KeyframeAnimationPoint<'b >>>>) {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KeyframeAnimation::VT_KEYFRAME_POINTS, keyframe_points);
   }
   #[inline]
+  pub fn add_media(&mut self, media: u32) {
+    self.fbb_.push_slot::<u32>(KeyframeAnimation::VT_MEDIA, media, 0);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> KeyframeAnimationBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     KeyframeAnimationBuilder {
@@ -3033,8 +4044,9 @@ impl core::fmt::Debug for KeyframeAnimation<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     let mut ds = f.debug_struct("KeyframeAnimation");
       ds.field("name", &self.name());
-      ds.field("media", &self.media());
+      ds.field("media_v1", &self.media_v1());
       ds.field("keyframe_points", &self.keyframe_points());
+      ds.field("media", &self.media());
       ds.finish()
   }
 }
@@ -3745,8 +4757,9 @@ impl<'a> flatbuffers::Follow<'a> for Media<'a> {
 }
 
 impl<'a> Media<'a> {
-  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ID_V1: flatbuffers::VOffsetT = 4;
   pub const VT_CONDITIONS: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3758,18 +4771,19 @@ impl<'a> Media<'a> {
     args: &'args MediaArgs<'args>
   ) -> flatbuffers::WIPOffset<Media<'bldr>> {
     let mut builder = MediaBuilder::new(_fbb);
-    if let Some(x) = args.conditions { builder.add_conditions(x); }
     builder.add_id(args.id);
+    if let Some(x) = args.conditions { builder.add_conditions(x); }
+    builder.add_id_v1(args.id_v1);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn id(&self) -> u8 {
+  pub fn id_v1(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(Media::VT_ID, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u8>(Media::VT_ID_V1, Some(0)).unwrap()}
   }
   #[inline]
   pub fn conditions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Condition<'a>>>> {
@@ -3778,6 +4792,13 @@ impl<'a> Media<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Condition>>>>(Media::VT_CONDITIONS, None)}
   }
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Media::VT_ID, Some(0)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Media<'_> {
@@ -3787,22 +4808,25 @@ impl flatbuffers::Verifiable for Media<'_> {
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u8>("id", Self::VT_ID, false)?
+     .visit_field::<u8>("id_v1", Self::VT_ID_V1, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Condition>>>>("conditions", Self::VT_CONDITIONS, false)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
      .finish();
     Ok(())
   }
 }
 pub struct MediaArgs<'a> {
-    pub id: u8,
+    pub id_v1: u8,
     pub conditions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Condition<'a>>>>>,
+    pub id: u32,
 }
 impl<'a> Default for MediaArgs<'a> {
   #[inline]
   fn default() -> Self {
     MediaArgs {
-      id: 0,
+      id_v1: 0,
       conditions: None,
+      id: 0,
     }
   }
 }
@@ -3813,14 +4837,18 @@ pub struct MediaBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
 }
 impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MediaBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_id(&mut self, id: u8) {
-    self.fbb_.push_slot::<u8>(Media::VT_ID, id, 0);
+  pub fn add_id_v1(&mut self, id_v1: u8) {
+    self.fbb_.push_slot::<u8>(Media::VT_ID_V1, id_v1, 0);
   }
   #[inline]
   pub fn add_conditions(&mut self, conditions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Condition<'b >>>>) {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Media::VT_CONDITIONS, conditions);
   }
   #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(Media::VT_ID, id, 0);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MediaBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     MediaBuilder {
@@ -3838,7 +4866,156 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MediaBuilder<'a, 'b, A> {
 impl core::fmt::Debug for Media<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     let mut ds = f.debug_struct("Media");
+      ds.field("id_v1", &self.id_v1());
+      ds.field("conditions", &self.conditions());
+      ds.field("id", &self.id());
+      ds.finish()
+  }
+}
+pub enum ContainerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Container<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Container<'a> {
+  type Inner = Container<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Container<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_PARENT: flatbuffers::VOffsetT = 8;
+  pub const VT_CONDITIONS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Container { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ContainerArgs<'args>
+  ) -> flatbuffers::WIPOffset<Container<'bldr>> {
+    let mut builder = ContainerBuilder::new(_fbb);
+    if let Some(x) = args.conditions { builder.add_conditions(x); }
+    builder.add_parent(args.parent);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_id(args.id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Container::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Container::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn parent(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Container::VT_PARENT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn conditions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Condition<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Condition>>>>(Container::VT_CONDITIONS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Container<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<u32>("parent", Self::VT_PARENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Condition>>>>("conditions", Self::VT_CONDITIONS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ContainerArgs<'a> {
+    pub id: u32,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub parent: u32,
+    pub conditions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Condition<'a>>>>>,
+}
+impl<'a> Default for ContainerArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ContainerArgs {
+      id: 0,
+      name: None,
+      parent: 0,
+      conditions: None,
+    }
+  }
+}
+
+pub struct ContainerBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ContainerBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(Container::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Container::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_parent(&mut self, parent: u32) {
+    self.fbb_.push_slot::<u32>(Container::VT_PARENT, parent, 0);
+  }
+  #[inline]
+  pub fn add_conditions(&mut self, conditions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Condition<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Container::VT_CONDITIONS, conditions);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ContainerBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ContainerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Container<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Container<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Container");
       ds.field("id", &self.id());
+      ds.field("name", &self.name());
+      ds.field("parent", &self.parent());
       ds.field("conditions", &self.conditions());
       ds.finish()
   }
@@ -3865,6 +5042,9 @@ impl<'a> StyleSheet<'a> {
   pub const VT_STYLES: flatbuffers::VOffsetT = 10;
   pub const VT_DESIGN_WIDTH: flatbuffers::VOffsetT = 12;
   pub const VT_ALLOW_INHERIT: flatbuffers::VOffsetT = 14;
+  pub const VT_DESIGN_MODE: flatbuffers::VOffsetT = 16;
+  pub const VT_FORMAT_VERSION: flatbuffers::VOffsetT = 18;
+  pub const VT_CONTAINERS: flatbuffers::VOffsetT = 20;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3876,10 +5056,13 @@ impl<'a> StyleSheet<'a> {
     args: &'args StyleSheetArgs<'args>
   ) -> flatbuffers::WIPOffset<StyleSheet<'bldr>> {
     let mut builder = StyleSheetBuilder::new(_fbb);
+    if let Some(x) = args.containers { builder.add_containers(x); }
+    if let Some(x) = args.design_mode { builder.add_design_mode(x); }
     if let Some(x) = args.styles { builder.add_styles(x); }
     if let Some(x) = args.medias { builder.add_medias(x); }
     if let Some(x) = args.keyframes { builder.add_keyframes(x); }
     if let Some(x) = args.fonts { builder.add_fonts(x); }
+    builder.add_format_version(args.format_version);
     builder.add_design_width(args.design_width);
     builder.add_allow_inherit(args.allow_inherit);
     builder.finish()
@@ -3928,6 +5111,27 @@ impl<'a> StyleSheet<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(StyleSheet::VT_ALLOW_INHERIT, Some(false)).unwrap()}
   }
+  #[inline]
+  pub fn design_mode(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StyleSheet::VT_DESIGN_MODE, None)}
+  }
+  #[inline]
+  pub fn format_version(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(StyleSheet::VT_FORMAT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn containers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Container<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Container>>>>(StyleSheet::VT_CONTAINERS, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for StyleSheet<'_> {
@@ -3943,6 +5147,9 @@ impl flatbuffers::Verifiable for StyleSheet<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Style>>>>("styles", Self::VT_STYLES, false)?
      .visit_field::<u16>("design_width", Self::VT_DESIGN_WIDTH, false)?
      .visit_field::<bool>("allow_inherit", Self::VT_ALLOW_INHERIT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("design_mode", Self::VT_DESIGN_MODE, false)?
+     .visit_field::<u16>("format_version", Self::VT_FORMAT_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Container>>>>("containers", Self::VT_CONTAINERS, false)?
      .finish();
     Ok(())
   }
@@ -3954,6 +5161,9 @@ pub struct StyleSheetArgs<'a> {
     pub styles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Style<'a>>>>>,
     pub design_width: u16,
     pub allow_inherit: bool,
+    pub design_mode: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub format_version: u16,
+    pub containers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Container<'a>>>>>,
 }
 impl<'a> Default for StyleSheetArgs<'a> {
   #[inline]
@@ -3965,6 +5175,9 @@ impl<'a> Default for StyleSheetArgs<'a> {
       styles: None,
       design_width: 0,
       allow_inherit: false,
+      design_mode: None,
+      format_version: 0,
+      containers: None,
     }
   }
 }
@@ -3999,6 +5212,18 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StyleSheetBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<bool>(StyleSheet::VT_ALLOW_INHERIT, allow_inherit, false);
   }
   #[inline]
+  pub fn add_design_mode(&mut self, design_mode: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StyleSheet::VT_DESIGN_MODE, design_mode);
+  }
+  #[inline]
+  pub fn add_format_version(&mut self, format_version: u16) {
+    self.fbb_.push_slot::<u16>(StyleSheet::VT_FORMAT_VERSION, format_version, 0);
+  }
+  #[inline]
+  pub fn add_containers(&mut self, containers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Container<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StyleSheet::VT_CONTAINERS, containers);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StyleSheetBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     StyleSheetBuilder {
@@ -4022,6 +5247,9 @@ impl core::fmt::Debug for StyleSheet<'_> {
       ds.field("styles", &self.styles());
       ds.field("design_width", &self.design_width());
       ds.field("allow_inherit", &self.allow_inherit());
+      ds.field("design_mode", &self.design_mode());
+      ds.field("format_version", &self.format_version());
+      ds.field("containers", &self.containers());
       ds.finish()
   }
 }
@@ -4085,16 +5313,28 @@ pub unsafe fn root_as_style_sheet_unchecked(buf: &[u8]) -> StyleSheet {
 pub unsafe fn size_prefixed_root_as_style_sheet_unchecked(buf: &[u8]) -> StyleSheet {
   flatbuffers::size_prefixed_root_unchecked::<StyleSheet>(buf)
 }
+pub const STYLE_SHEET_IDENTIFIER: &str = "TCSS";
+
+#[inline]
+pub fn style_sheet_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, STYLE_SHEET_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn style_sheet_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, STYLE_SHEET_IDENTIFIER, true)
+}
+
 #[inline]
 pub fn finish_style_sheet_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
     fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     root: flatbuffers::WIPOffset<StyleSheet<'a>>) {
-  fbb.finish(root, None);
+  fbb.finish(root, Some(STYLE_SHEET_IDENTIFIER));
 }
 
 #[inline]
 pub fn finish_size_prefixed_style_sheet_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<StyleSheet<'a>>) {
-  fbb.finish_size_prefixed(root, None);
+  fbb.finish_size_prefixed(root, Some(STYLE_SHEET_IDENTIFIER));
 }
 }  // pub mod Styles
 
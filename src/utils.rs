@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
 
 use html5ever::{namespace_url, ns, LocalName, QualName};
 use pcre2::bytes::Regex;
@@ -10,10 +11,16 @@ use swc_core::ecma::{
   },
   visit::{Visit, VisitWith},
 };
+use swc_core::common::DUMMY_SP;
+use swc_core::ecma::ast::{Bool, ExprOrSpread, Ident, KeyValueProp, Lit};
 use serde_json::Value;
 use flatbuffers::{FlatBufferBuilder, WIPOffset, UnionWIPOffset};
 
-use crate::{constants::SelectorType, stylesheet_generated::styles};
+use crate::{
+  constants::{AttributeOperator, Pseudo, SelectorType, FORMAT_VERSION},
+  generate_expr_lit_num, generate_expr_lit_str_raw,
+  stylesheet_generated::styles,
+};
 
 pub fn lowercase_first(s: &mut str) {
   if let Some(c) = s.get_mut(0..1) {
@@ -75,101 +82,239 @@ pub fn hex_to_argb(hex: &str) -> Result<u32, String> {
 pub enum TSelector {
   Selector(SelectorType),
   String(String),
-  Array(Vec<String>),
+  // 复合选择器，如 view#header.item[data-active]
+  Compound(CompoundSelector),
 }
 
-// 分割选择器
-pub fn split_selector(selector: &str) -> Vec<TSelector> {
-  let mut result = Vec::new();
-  let mut current_word = String::new();
-  let mut buffer = String::new();
-
-  for c in selector.chars() {
-    if c == ' ' || c == '>' {
-      if !current_word.is_empty() {
-        let split_selector = split_classes(current_word.as_str());
-        match split_selector {
-          TSelector::Selector(_) => {}
-          TSelector::String(selector) => {
-            result.push(TSelector::String(selector));
-          }
-          TSelector::Array(selectors) => {
-            let length = selectors.len();
-            for (index, selector) in selectors.iter().enumerate() {
-              result.push(TSelector::String(selector.clone()));
-              if index != length - 1 {
-                result.push(TSelector::Selector(SelectorType::Multiple))
-              }
-            }
-          }
-        }
-        current_word.clear();
-      }
-      buffer.push(c);
-      if buffer == " > " {
-        // 子选择器
-        result.push(TSelector::Selector(SelectorType::Parent));
-        buffer.clear();
-      }
-    } else {
-      current_word.push(c);
-      if buffer == ' '.to_string() {
-        // 后代选择器
-        result.push(TSelector::Selector(SelectorType::Ancestor));
-        buffer.clear();
-      }
+// 复合选择器中的标签、id、类名及属性选择器
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CompoundSelector {
+  // 标签名，通配符为 *
+  pub tag: Option<String>,
+  pub id: Option<String>,
+  pub classes: Vec<String>,
+  pub attributes: Vec<AttributeSelector>,
+  // :not() 中的复合选择器，均不匹配时才匹配
+  pub negations: Vec<CompoundSelector>,
+  // 伪类、伪元素，伪元素在最后
+  pub pseudos: Vec<Pseudo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AttributeSelector {
+  pub name: String,
+  pub operator: AttributeOperator,
+  // Exists 时为 None
+  pub value: Option<String>,
+  // 带有 i 标记，如 [type="text" i]
+  pub case_insensitive: bool,
+}
+
+impl CompoundSelector {
+  // 能否用旧格式表示，只有最后一个复合选择器（位于开头的 subject）可以带一个伪类、伪元素，记录在 Style 的 pseudo 中
+  pub fn has_legacy_form(&self, is_subject: bool) -> bool {
+    self.attributes.is_empty()
+      && self.negations.is_empty()
+      && self.pseudos.len() <= if is_subject { 1 } else { 0 }
+  }
+
+  // 转换为旧格式的字符串，属性选择器、:not()、伪类无法表示，从右往左排列，如 view#header.item => ["item", "#header", "view"]
+  pub fn to_legacy_strings(&self) -> Vec<String> {
+    let mut result = vec![];
+    result.extend(self.tag.clone());
+    result.extend(self.id.as_ref().map(|id| format!("#{}", id)));
+    result.extend(self.classes.iter().cloned());
+    if result.is_empty() {
+      result.push("*".to_string());
     }
+    result.reverse();
+    result
   }
 
-  if !current_word.is_empty() {
-    let split_selector = split_classes(current_word.as_str());
-    match split_selector {
-      TSelector::Selector(_) => {}
-      TSelector::String(selector) => {
-        result.push(TSelector::String(selector));
-      }
-      TSelector::Array(selectors) => {
-        let length = selectors.len();
-        for (index, selector) in selectors.iter().enumerate() {
-          result.push(TSelector::String(selector.clone()));
-          if index != length - 1 {
-            result.push(TSelector::Selector(SelectorType::Multiple))
-          }
-        }
-      }
+  pub fn to_expr(&self) -> Vec<PropOrSpread> {
+    let mut props = vec![];
+    if let Some(tag) = &self.tag {
+      props.push(key_value_prop("tag", generate_expr_lit_str_raw!(tag.clone())));
     }
+    if let Some(id) = &self.id {
+      props.push(key_value_prop("id", generate_expr_lit_str_raw!(id.clone())));
+    }
+    if !self.classes.is_empty() {
+      props.push(key_value_prop(
+        "classes",
+        Expr::Array(ArrayLit {
+          span: DUMMY_SP,
+          elems: self
+            .classes
+            .iter()
+            .map(|class| {
+              Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(generate_expr_lit_str_raw!(class.clone())),
+              })
+            })
+            .collect(),
+        }),
+      ));
+    }
+    if !self.attributes.is_empty() {
+      props.push(key_value_prop(
+        "attributes",
+        Expr::Array(ArrayLit {
+          span: DUMMY_SP,
+          elems: self
+            .attributes
+            .iter()
+            .map(|attribute| {
+              Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Object(ObjectLit {
+                  span: DUMMY_SP,
+                  props: attribute.to_expr(),
+                })),
+              })
+            })
+            .collect(),
+        }),
+      ));
+    }
+    if !self.pseudos.is_empty() {
+      props.push(key_value_prop(
+        "pseudos",
+        Expr::Array(ArrayLit {
+          span: DUMMY_SP,
+          elems: self
+            .pseudos
+            .iter()
+            .map(|pseudo| {
+              Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Object(ObjectLit {
+                  span: DUMMY_SP,
+                  props: pseudo_to_expr(pseudo),
+                })),
+              })
+            })
+            .collect(),
+        }),
+      ));
+    }
+    if !self.negations.is_empty() {
+      props.push(key_value_prop(
+        "negations",
+        Expr::Array(ArrayLit {
+          span: DUMMY_SP,
+          elems: self
+            .negations
+            .iter()
+            .map(|negation| {
+              Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Object(ObjectLit {
+                  span: DUMMY_SP,
+                  props: negation.to_expr(),
+                })),
+              })
+            })
+            .collect(),
+        }),
+      ));
+    }
+    props
   }
+}
 
-  if !buffer.is_empty() {
-    result.push(TSelector::String(buffer.clone()));
+impl AttributeSelector {
+  pub fn to_expr(&self) -> Vec<PropOrSpread> {
+    let mut props = vec![
+      key_value_prop("name", generate_expr_lit_str_raw!(self.name.clone())),
+      key_value_prop("operator", generate_expr_lit_num!(self.operator.to_f64())),
+    ];
+    if let Some(value) = &self.value {
+      props.push(key_value_prop("value", generate_expr_lit_str_raw!(value.clone())));
+    }
+    if self.case_insensitive {
+      props.push(key_value_prop(
+        "case_insensitive",
+        Expr::Lit(Lit::Bool(Bool { span: DUMMY_SP, value: true })),
+      ));
+    }
+    props
   }
+}
 
-  result.reverse();
+// 与样式上的 pseudo、pseudo_key、pseudo_val 字段一致
+fn pseudo_to_expr(pseudo: &Pseudo) -> Vec<PropOrSpread> {
+  let mut props = vec![key_value_prop("pseudo", generate_expr_lit_num!(pseudo.to_f64()))];
+  if let Pseudo::NthChild(a, b, is_first) | Pseudo::NthOfType(a, b, is_first) = pseudo {
+    props.push(key_value_prop(
+      "pseudo_key",
+      Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: vec![
+          generate_expr_lit_num!(*a),
+          generate_expr_lit_num!(*b),
+          Expr::Lit(Lit::Bool(Bool { span: DUMMY_SP, value: *is_first })),
+        ]
+        .into_iter()
+        .map(|expr| Some(ExprOrSpread { spread: None, expr: Box::new(expr) }))
+        .collect(),
+      }),
+    ));
+    props.push(key_value_prop("pseudo_val", generate_expr_lit_str_raw!(nth_child_value(*a, *b))));
+  }
+  props
+}
 
-  result
+pub fn key_value_prop(key: &str, value: Expr) -> PropOrSpread {
+  PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+    key: PropName::Ident(Ident::new(key.into(), DUMMY_SP)),
+    value: Box::new(value),
+  })))
 }
 
-// 分割类名 .a.b.c => ["a", "b", "c"]
-fn split_classes(input: &str) -> TSelector {
-  let mut matches = Vec::new();
-  let mut current_class = String::new();
-  for char in input.chars() {
-    if char == '.' {
-      if !current_class.is_empty() {
-        matches.push(current_class.clone());
-        current_class.clear();
-      }
+// nth-child(an+b) 转换为 pseudo_val
+pub fn nth_child_value(a: i32, b: i32) -> String {
+  if a == 0 && b == 0 {
+    "".to_string()
+  } else if a == 0 {
+    format!("{}", b)
+  } else if b == 0 {
+    format!("{}n", a)
+  } else {
+    if a == 1 {
+      format!("n+{}", b)
     } else {
-      current_class.push(char);
+      format!("{}n+{}", a, b)
     }
   }
-  if !current_class.is_empty() {
-    matches.push(current_class);
-  }
-  if matches.len() > 1 {
-    TSelector::Array(matches)
-  } else {
-    TSelector::String(input.replace(".", ""))
+}
+
+// 以 root 为根目录读取 @import 的文件，相对地址基于所在文件的目录，以 / 开头的地址基于 root，返回的文件名为绝对路径
+pub fn root_dir_import_resolver(root: &str) -> impl Fn(&str, &str) -> Result<(String, String), String> {
+  let root = std::fs::canonicalize(root).unwrap_or_else(|_| PathBuf::from(root));
+  move |url, importer| {
+    let path = match url.strip_prefix('/') {
+      Some(url) => root.join(url),
+      None => root
+        .join(Path::new(importer).parent().unwrap_or(Path::new("")))
+        .join(url),
+    };
+    // 去掉路径中的 . 和 ..，保证同一文件的文件名一致，用于检测循环 @import
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+      match component {
+        Component::CurDir => {}
+        Component::ParentDir => {
+          normalized.pop();
+        }
+        _ => normalized.push(component),
+      }
+    }
+    match std::fs::read_to_string(&normalized) {
+      Ok(code) => Ok((normalized.to_string_lossy().to_string(), code)),
+      Err(_) => Err(format!("cannot find imported file `{}`", url)),
+    }
   }
 }
 
@@ -426,20 +571,85 @@ fn create_flatbuffer_condition<'a>(builder: &mut FlatBufferBuilder<'a>, cond: &s
   condition_value
 }
 
+fn create_flatbuffer_compound_selector<'a>(builder: &mut FlatBufferBuilder<'a>, compound: &serde_json::Value) -> WIPOffset<styles::CompoundSelector<'a>> {
+  let tag = compound["tag"].as_str().map(|tag| builder.create_string(tag));
+  let id = compound["id"].as_str().map(|id| builder.create_string(id));
+  let classes = compound["classes"].as_array().map(|classes| {
+    let classes = classes
+      .iter()
+      .map(|class| builder.create_string(class.as_str().unwrap_or_default()))
+      .collect::<Vec<_>>();
+    builder.create_vector(&classes)
+  });
+  let attributes = compound["attributes"].as_array().map(|attributes| {
+    let attributes = attributes
+      .iter()
+      .map(|attribute| {
+        let name = builder.create_string(attribute["name"].as_str().unwrap_or_default());
+        let value = attribute["value"].as_str().map(|value| builder.create_string(value));
+        styles::AttributeSelector::create(builder, &styles::AttributeSelectorArgs {
+          name: Some(name),
+          operator: attribute["operator"].as_u64().unwrap_or(0) as u8,
+          value,
+          case_insensitive: attribute["case_insensitive"].as_bool().unwrap_or(false),
+        })
+      })
+      .collect::<Vec<_>>();
+    builder.create_vector(&attributes)
+  });
+  let pseudos = compound["pseudos"].as_array().map(|pseudos| {
+    let pseudos = pseudos
+      .iter()
+      .map(|pseudo| {
+        let pseudo_key = pseudo["pseudo_key"].as_array().map(|keys| {
+          let keys = keys
+            .iter()
+            .map(|key| create_flatbuffer_pseudo_key(builder, key))
+            .collect::<Vec<_>>();
+          builder.create_vector(&keys)
+        });
+        let pseudo_val = pseudo["pseudo_val"].as_str().map(|value| builder.create_string(value));
+        styles::PseudoSelector::create(builder, &styles::PseudoSelectorArgs {
+          pseudo: pseudo["pseudo"].as_u64().unwrap_or(0) as u8,
+          pseudo_key,
+          pseudo_val,
+        })
+      })
+      .collect::<Vec<_>>();
+    builder.create_vector(&pseudos)
+  });
+  let negations = compound["negations"].as_array().map(|negations| {
+    let negations = negations
+      .iter()
+      .map(|negation| create_flatbuffer_compound_selector(builder, negation))
+      .collect::<Vec<_>>();
+    builder.create_vector(&negations)
+  });
+  styles::CompoundSelector::create(builder, &styles::CompoundSelectorArgs {
+    tag,
+    id,
+    classes,
+    attributes,
+    negations,
+    pseudos,
+  })
+}
+
 fn create_flatbuffer_pseudo_key<'a>(builder: &mut FlatBufferBuilder<'a>, key: &serde_json::Value) -> WIPOffset<styles::PseudoKey<'a>> {
   if key.is_i64() {
-    let integer_value = key.as_i64().unwrap() as i8;
+    let integer_value = key.as_i64().unwrap() as i32;
     styles::PseudoKey::create(builder, &styles::PseudoKeyArgs {
       integer_value,
       bool_value: false,
       is_int: true,
+      ..Default::default()
     })
   } else {
     let bool_value = key.as_bool().unwrap();
     styles::PseudoKey::create(builder, &styles::PseudoKeyArgs {
-      integer_value: 0,
       bool_value,
       is_int: false,
+      ..Default::default()
     })
   }
 }
@@ -466,9 +676,49 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
 
       let font_family = builder.create_string(font_family);
       let src = builder.create_string(src);
+      let sources: Vec<WIPOffset<styles::FontSource>> = f["sources"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .map(|source| {
+          let url = source["url"].as_str().map(|url| builder.create_string(url));
+          let local = source["local"].as_str().map(|local| builder.create_string(local));
+          let format = source["format"].as_str().map(|format| builder.create_string(format));
+          styles::FontSource::create(&mut builder, &styles::FontSourceArgs { url, local, format })
+        })
+        .collect();
+      let sources = if !sources.is_empty() {
+        Some(builder.create_vector(&sources))
+      } else {
+        None
+      };
+      let unicode_range: Vec<WIPOffset<styles::UnicodeRange>> = f["unicodeRange"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .map(|range| {
+          styles::UnicodeRange::create(&mut builder, &styles::UnicodeRangeArgs {
+            start: range[0].as_u64().unwrap_or(0) as u32,
+            end: range[1].as_u64().unwrap_or(0) as u32,
+          })
+        })
+        .collect();
+      let unicode_range = if !unicode_range.is_empty() {
+        Some(builder.create_vector(&unicode_range))
+      } else {
+        None
+      };
+      let font_display = f["fontDisplay"].as_str().map(|font_display| builder.create_string(font_display));
       let font = styles::Font::create(&mut builder, &styles::FontArgs {
         font_family: Some(font_family),
         src: Some(src),
+        font_weight: f["fontWeight"].as_u64().map(|font_weight| font_weight as u8),
+        sources,
+        font_style: f["fontStyle"].as_u64().map(|font_style| font_style as u8),
+        font_weight_min: f["fontWeightRange"][0].as_u64().unwrap_or(0) as u16,
+        font_weight_max: f["fontWeightRange"][1].as_u64().unwrap_or(0) as u16,
+        unicode_range,
+        font_display,
       });
       font
     }).collect();
@@ -482,7 +732,7 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
     .map(|k| {
       let name = k["name"].as_str().unwrap_or("");
       let name_offset = builder.create_string(name);
-      let media = k["media"].as_u64().unwrap_or(0) as u8;
+      let media = k["media"].as_u64().unwrap_or(0) as u32;
       
       // 处理关键帧点
       let keyframe_points: Vec<WIPOffset<styles::KeyframeAnimationPoint>> = k["keyframe"]
@@ -499,7 +749,7 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
             .iter()
             .map(|decl| {
               let decl_array = decl.as_array().unwrap();
-              let property_id = decl_array[0].as_u64().unwrap() as u8;
+              let property_id = decl_array[0].as_u64().unwrap() as u16;
               
               let (value_type, value) = process_flatbuffer_value(&mut builder, &decl_array[1]);
               
@@ -513,7 +763,8 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
                 property_id: property_id,
                 value_type: value_type,
                 value: Some(value),
-                flag: property_flag
+                flag: property_flag,
+                ..Default::default()
               })
             }).collect();
           
@@ -533,6 +784,7 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
         name: Some(name_offset),
         media: media,
         keyframe_points: Some(keyframe_points),
+        ..Default::default()
       })
     })
     .collect();
@@ -552,14 +804,42 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
         .collect();
       let conditions_vector = builder.create_vector(&conditions);
       let media = styles::Media::create(&mut builder, &styles::MediaArgs {
-        id: m["id"].as_u64().unwrap() as u8,
+        id: m["id"].as_u64().unwrap() as u32,
         conditions: Some(conditions_vector),
+        ..Default::default()
       });
       media
     })
     .collect();
   let medias = builder.create_vector(&medias);
 
+  let containers: Vec<WIPOffset<styles::Container>> = json["containers"]
+    .as_array()
+    .unwrap_or(&vec![])
+    .iter()
+    .map(|c| {
+      let conditions: Vec<_> = c["conditions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|cond_array| create_flatbuffer_condition(&mut builder, cond_array))
+        .collect();
+      let conditions_vector = builder.create_vector(&conditions);
+      let name = c["name"].as_str().map(|name| builder.create_string(name));
+      styles::Container::create(&mut builder, &styles::ContainerArgs {
+        id: c["id"].as_u64().unwrap() as u32,
+        name,
+        parent: c["parent"].as_u64().unwrap_or(0) as u32,
+        conditions: Some(conditions_vector),
+      })
+    })
+    .collect();
+  let containers = if !containers.is_empty() {
+    Some(builder.create_vector(&containers))
+  } else {
+    None
+  };
+
   let styles: Vec<WIPOffset<styles::Style>> = json["styles"]
     .as_array()
     .unwrap()
@@ -577,13 +857,24 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
                 string_value: Some(string_offset),
                 integer_value: 0,
                 is_string: true,
+                compound: None,
               })
             },
             Value::Number(n) => styles::Selector::create(&mut builder, &styles::SelectorArgs {
               string_value: None,
               integer_value: n.as_u64().unwrap() as u8,
               is_string: false,
+              compound: None,
             }),
+            Value::Object(_) => {
+              let compound = create_flatbuffer_compound_selector(&mut builder, sel);
+              styles::Selector::create(&mut builder, &styles::SelectorArgs {
+                string_value: None,
+                integer_value: 0,
+                is_string: false,
+                compound: Some(compound),
+              })
+            },
             _ => panic!("Invalid selector type"),
           }
         }).collect();
@@ -595,7 +886,7 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
         .iter()
         .map(|decl| {
           let decl_array = decl.as_array().unwrap();
-          let property_id = decl_array[0].as_u64().unwrap() as u8;
+          let property_id = decl_array[0].as_u64().unwrap() as u16;
           
           let (value_type, value) = process_flatbuffer_value(&mut builder, &decl_array[1]);
           
@@ -609,7 +900,8 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
             property_id: property_id,
             value_type: value_type,
             value: Some(value),
-            flag: property_flag
+            flag: property_flag,
+            ..Default::default()
           })
         }).collect();
         let declarations = builder.create_vector(&declarations);
@@ -640,7 +932,7 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
 
         styles::Style::create(&mut builder, &styles::StyleArgs {
           declarations: Some(declarations),
-          media: style["media"].as_u64().unwrap() as u8,
+          media: style["media"].as_u64().unwrap() as u32,
           pseudo: style["pseudo"].as_u64().unwrap_or(0) as u8,
           selector: Some(selector),
           pseudo_key: if pseudo_key.len() > 0 {
@@ -654,11 +946,19 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
           } else {
             None
           },
+          has_env: style["has_env"].as_bool().unwrap_or(false),
+          specificity: style["specificity"].as_u64().unwrap_or(0) as u32,
+          order: style["order"].as_u64().unwrap_or(0) as u32,
+          layer: style["layer"].as_u64().unwrap_or(0) as u32,
+          container: style["container"].as_u64().unwrap_or(0) as u32,
+          important_layer: style["important_layer"].as_u64().unwrap_or(0) as u32,
+          ..Default::default()
         })
     }).collect();
     let styles = builder.create_vector(&styles);
     let design_width = json["design_width"].as_u64().unwrap_or(0) as u16;
     let allow_inherit = json["allow_inherit"].as_bool().unwrap_or(false);
+    let design_mode = json["design_mode"].as_str().map(|design_mode| builder.create_string(design_mode));
     let stylesheet = styles::StyleSheet::create(&mut builder, &styles::StyleSheetArgs {
       fonts: Some(fonts),
       keyframes: Some(keyframes),
@@ -666,8 +966,11 @@ pub fn convert_json_to_flatbuffer(json_str: &str) -> Result<Vec<u8>, serde_json:
       styles: Some(styles),
       design_width: design_width,
       allow_inherit: allow_inherit,
+      design_mode,
+      format_version: FORMAT_VERSION,
+      containers,
     });
 
-    builder.finish(stylesheet, None);
+    styles::finish_style_sheet_buffer(&mut builder, stylesheet);
     Ok(builder.finished_data().to_vec())
 }
@@ -29,14 +29,16 @@ impl Hash for SpanKey {
   }
 }
 
-// 辅助函数，处理样式值并添加到索引映射中
-fn process_style_values(
+// 辅助函数，遍历样式值转换后的有效表达式，回调参数为 (样式值下标, 属性 id, 表达式, 标记)
+fn for_each_style_value<F>(
   style_values: Vec<StyleValueType>,
-  index_map: &mut IndexMap<u32, (Box<Expr>, ValueFlag)>,
   platform: &Platform,
   base_flag: ValueFlag,
-) {
-  style_values.into_iter().for_each(|style_value| {
+  mut callback: F,
+) where
+  F: FnMut(usize, u32, Expr, ValueFlag),
+{
+  style_values.into_iter().enumerate().for_each(|(index, style_value)| {
     // 匹配style_value是否Variable类型
     let variable_flag = if let StyleValueType::Variable(_) = style_value.clone() {
       let prop = style_value.to_expr(platform.clone());
@@ -44,7 +46,7 @@ fn process_style_values(
         if let Expr::Invalid(_) = expr {
           return;
         }
-        index_map.insert(id.clone() as u32, (Box::new(expr), base_flag | ValueFlag::VARIABLE));
+        callback(index, id as u32, expr, base_flag | ValueFlag::VARIABLE);
       }
       return;
     } else {
@@ -57,26 +59,55 @@ fn process_style_values(
         if let Expr::Invalid(_) = expr {
           return;
         }
-        index_map.insert(id.clone() as u32, (Box::new(expr), base_flag | variable_flag));
+        callback(index, id as u32, expr, base_flag | variable_flag);
       }
       PropertyTuple::Array(prop_arr) => prop_arr.into_iter().for_each(|(id, expr)| {
         if let Expr::Invalid(_) = expr {
           return;
         }
-        index_map.insert(id.clone() as u32, (Box::new(expr), base_flag | variable_flag));
+        callback(index, id as u32, expr, base_flag | variable_flag);
       }),
       _ => {}
     }
   });
 }
 
-pub fn parse_style_values(
+// 辅助函数，处理样式值并添加到索引映射中
+fn process_style_values(
+  style_values: Vec<StyleValueType>,
+  index_map: &mut IndexMap<u32, (Box<Expr>, ValueFlag)>,
+  platform: &Platform,
+  base_flag: ValueFlag,
+) {
+  for_each_style_value(style_values, platform, base_flag, |_, id, expr, flag| {
+    index_map.insert(id, (Box::new(expr), flag));
+  });
+}
+
+// 按 parse_style_values 输出的顺序，返回每条声明对应的来源，locations 与样式值一一对应
+pub fn parse_style_value_locations<T: Clone>(
   value: Vec<StyleValueType>,
+  locations: &[T],
   import_value: Vec<StyleValueType>,
+  import_locations: &[T],
   platform: Platform,
-) -> Vec<Option<ExprOrSpread>> {
-  let mut prop_or_spread = vec![];
+) -> Vec<T> {
+  let mut index_map = IndexMap::new();
+  for_each_style_value(value, &platform, ValueFlag::NONE, |index, id, _, _| {
+    index_map.insert(id, locations[index].clone());
+  });
+  for_each_style_value(import_value, &platform, ValueFlag::IMPORTANT, |index, id, _, _| {
+    index_map.insert(id, import_locations[index].clone());
+  });
+  index_map.into_values().collect()
+}
 
+// 合并普通样式值和 important 样式值，返回 属性 id -> (表达式, 标记) 的有序表
+pub fn collect_style_values(
+  value: Vec<StyleValueType>,
+  import_value: Vec<StyleValueType>,
+  platform: Platform,
+) -> IndexMap<u32, (Box<Expr>, ValueFlag)> {
   // 使用有序表
   let mut index_map = IndexMap::new();
   
@@ -85,6 +116,18 @@ pub fn parse_style_values(
   // 处理important样式值
   process_style_values(import_value, &mut index_map, &platform, ValueFlag::IMPORTANT);
 
+  index_map
+}
+
+pub fn parse_style_values(
+  value: Vec<StyleValueType>,
+  import_value: Vec<StyleValueType>,
+  platform: Platform,
+) -> Vec<Option<ExprOrSpread>> {
+  let mut prop_or_spread = vec![];
+
+  let index_map = collect_style_values(value, import_value, platform);
+
   index_map.into_iter().for_each(|(id, (expr, value_type))| {
     let id_num = id.clone() as u32;
     let mut elems = vec![